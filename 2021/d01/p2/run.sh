@@ -1 +0,0 @@
-cd sonar_sweep && cargo run -- ../../input.txt 3
@@ -0,0 +1,45 @@
+use anyhow::{Context, Result};
+use aoc_solution::Solution;
+
+const WINDOW_SIZE: usize = 3;
+
+/// Count how many times the sum of a sliding window of readings increases
+fn increase_count(readings: &[u32], window_size: usize) -> usize {
+    let mut increase_count = 0;
+    let mut prev_sum = u32::MAX;
+
+    for window in readings.windows(window_size) {
+        let window_sum: u32 = window.iter().sum();
+        if window_sum > prev_sum {
+            increase_count += 1;
+        }
+        prev_sum = window_sum;
+    }
+
+    increase_count
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<u32>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.parse().context("Invalid reading"))
+            .collect()
+    }
+
+    fn part1(readings: &Self::Input) -> Result<usize> {
+        Ok(increase_count(readings, 1))
+    }
+
+    fn part2(readings: &Self::Input) -> Result<usize> {
+        Ok(increase_count(readings, WINDOW_SIZE))
+    }
+}
+
+aoc_solution::expected_answers!(Day01);
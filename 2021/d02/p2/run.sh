@@ -1 +0,0 @@
-cd dive && cargo run -- ../../input.txt
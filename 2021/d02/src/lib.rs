@@ -0,0 +1,78 @@
+use anyhow::{anyhow, Context, Error, Result};
+use aoc_solution::Solution;
+
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+impl TryFrom<&str> for Command {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (cmd, amount) = value
+            .split_once(' ')
+            .ok_or(anyhow!("Invalid command: {}", value))?;
+        let amount = amount.parse().context("Invalid amount")?;
+
+        match cmd {
+            "forward" => Ok(Command::Forward(amount)),
+            "down" => Ok(Command::Down(amount)),
+            "up" => Ok(Command::Up(amount)),
+            _ => Err(anyhow!("Invalid command: {}", value)),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Position {
+    horizontal: i32,
+    depth: i32,
+    aim: i32,
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Command>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(Command::try_from).collect()
+    }
+
+    fn part1(commands: &Self::Input) -> Result<i32> {
+        let mut position = Position::default();
+
+        for cmd in commands {
+            match *cmd {
+                Command::Forward(amount) => position.horizontal += amount,
+                Command::Down(amount) => position.depth += amount,
+                Command::Up(amount) => position.depth -= amount,
+            }
+        }
+
+        Ok(position.horizontal * position.depth)
+    }
+
+    fn part2(commands: &Self::Input) -> Result<i32> {
+        let mut position = Position::default();
+
+        for cmd in commands {
+            match *cmd {
+                Command::Forward(amount) => {
+                    position.horizontal += amount;
+                    position.depth += position.aim * amount;
+                }
+                Command::Down(amount) => position.aim += amount,
+                Command::Up(amount) => position.aim -= amount,
+            }
+        }
+
+        Ok(position.horizontal * position.depth)
+    }
+}
+
+aoc_solution::expected_answers!(Day02);
@@ -1 +0,0 @@
-cd bin_diag && cargo run -- ../../input.txt 12
@@ -0,0 +1,106 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_solution::Solution;
+
+pub struct Report {
+    readings: Vec<u32>,
+    /// Number of bits in each reading
+    input_len: usize,
+}
+
+impl Report {
+    pub fn power_consumption(&self) -> u32 {
+        let mut bit_counts: Vec<usize> = vec![0; self.input_len];
+
+        for reading in self.readings.iter() {
+            for (i, count) in bit_counts.iter_mut().enumerate() {
+                if reading & (1 << (self.input_len - 1 - i)) > 0 {
+                    *count += 1;
+                }
+            }
+        }
+
+        let total = self.readings.len();
+        let gamma = bit_counts
+            .iter()
+            .fold(0, |acc, c| (acc << 1) | u32::from(2 * c >= total));
+
+        let invert_mask: u32 = (1u32 << self.input_len) - 1;
+        let epsilon = gamma ^ invert_mask;
+
+        gamma * epsilon
+    }
+
+    pub fn life_support_rating(&self) -> Result<u32> {
+        let oxy_rating = self.rating(true)?;
+        let co2_rating = self.rating(false)?;
+
+        Ok(oxy_rating * co2_rating)
+    }
+
+    fn rating(&self, most_common: bool) -> Result<u32> {
+        let mut subset: Vec<u32>;
+        let mut remaining = &self.readings[..];
+        let mut mask = 1u32 << (self.input_len - 1);
+
+        for _ in 0..self.input_len {
+            // count ones in column
+            let bits_set = remaining.iter().filter(|&x| x & mask > 0).count();
+
+            // ones considered most common in a tie, which works in favor of ones when
+            // most_common is true and in favor of zeroes when most_common is false
+            let ones_common = 2 * bits_set >= remaining.len();
+
+            // desired bit should be set when ones are most common and most common wins or when
+            // zeroes are most common and most common loses. otherwise, desired bit should not be set
+            let desired_bit = if ones_common == most_common { mask } else { 0 };
+
+            // collect numbers with desired bit (un)set
+            subset = remaining
+                .iter()
+                .filter(|&x| x & mask == desired_bit)
+                .copied()
+                .collect();
+            remaining = &subset;
+
+            if remaining.len() == 1 {
+                return Ok(remaining[0]);
+            }
+
+            // move bit mask to next column
+            mask >>= 1;
+        }
+
+        Err(anyhow!("Reached end of readings."))
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Report;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input_len = input.lines().next().map_or(0, str::len);
+        let readings = input
+            .lines()
+            .map(|r| u32::from_str_radix(r, 2).context("Invalid reading"))
+            .collect::<Result<_>>()?;
+
+        Ok(Report {
+            readings,
+            input_len,
+        })
+    }
+
+    fn part1(report: &Self::Input) -> Result<u32> {
+        Ok(report.power_consumption())
+    }
+
+    fn part2(report: &Self::Input) -> Result<u32> {
+        report.life_support_rating()
+    }
+}
+
+aoc_solution::expected_answers!(Day03);
@@ -1 +0,0 @@
-cd bingo && cargo run -- ../../input.txt
@@ -0,0 +1,122 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_solution::Solution;
+
+#[derive(Clone)]
+pub struct Board {
+    numbers: [u8; 25],
+    marked: [bool; 25],
+}
+
+impl Board {
+    pub fn new(numbers: [u8; 25]) -> Board {
+        Board {
+            numbers,
+            marked: [false; 25],
+        }
+    }
+
+    pub fn mark(&mut self, num: u8) {
+        if let Some(i) = self.numbers.iter().position(|&x| x == num) {
+            self.marked[i] = true;
+        }
+    }
+
+    pub fn winner(&self) -> bool {
+        self.marked[0..5].iter().all(|&x| x)
+            || self.marked[5..10].iter().all(|&x| x)
+            || self.marked[10..15].iter().all(|&x| x)
+            || self.marked[15..20].iter().all(|&x| x)
+            || self.marked[20..25].iter().all(|&x| x)
+            || self.marked.iter().step_by(5).all(|&x| x)
+            || self.marked.iter().skip(1).step_by(5).all(|&x| x)
+            || self.marked.iter().skip(2).step_by(5).all(|&x| x)
+            || self.marked.iter().skip(3).step_by(5).all(|&x| x)
+            || self.marked.iter().skip(4).step_by(5).all(|&x| x)
+    }
+
+    pub fn score(&self) -> u32 {
+        self.marked
+            .iter()
+            .enumerate()
+            .filter(|(_, &x)| !x)
+            .map(|(i, _)| self.numbers[i] as u32)
+            .sum()
+    }
+}
+
+pub struct Bingo {
+    draws: Vec<u8>,
+    boards: Vec<Board>,
+}
+
+impl Bingo {
+    /// Scores of the winning boards, in the order they win
+    fn winning_scores(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut boards = self.boards.clone();
+        let mut winners = vec![false; boards.len()];
+
+        self.draws.iter().flat_map(move |&draw| {
+            let mut scores = Vec::new();
+
+            for (i, board) in boards.iter_mut().enumerate() {
+                if winners[i] {
+                    continue;
+                }
+
+                board.mark(draw);
+                if board.winner() {
+                    winners[i] = true;
+                    scores.push(board.score() * (draw as u32));
+                }
+            }
+
+            scores
+        })
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Bingo;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = input.lines().filter(|line| !line.is_empty());
+
+        let draws: Vec<u8> = lines
+            .next()
+            .ok_or(anyhow!("Missing draws"))?
+            .split(',')
+            .map(|x| x.parse().context("Invalid draw"))
+            .collect::<Result<_>>()?;
+
+        let board_nums: Vec<u8> = lines
+            .flat_map(|line| line.split_whitespace().map(|x| x.parse()))
+            .collect::<Result<_, _>>()
+            .context("Invalid board number")?;
+
+        let boards = board_nums
+            .chunks(25)
+            .map(|chunk| {
+                chunk
+                    .try_into()
+                    .map(Board::new)
+                    .context("Incomplete board")
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Bingo { draws, boards })
+    }
+
+    fn part1(bingo: &Self::Input) -> Result<u32> {
+        bingo.winning_scores().next().ok_or(anyhow!("No board won."))
+    }
+
+    fn part2(bingo: &Self::Input) -> Result<u32> {
+        bingo.winning_scores().last().ok_or(anyhow!("No board won."))
+    }
+}
+
+aoc_solution::expected_answers!(Day04);
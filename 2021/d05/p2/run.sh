@@ -1 +0,0 @@
-cd hydro && cargo run -- ../../input.txt
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_solution::Solution;
+
+pub type Point = aoc_geometry::Point<i32>;
+
+pub struct Vent(Point, Point);
+
+impl Vent {
+    fn is_axis_aligned(&self) -> bool {
+        self.0.x == self.1.x || self.0.y == self.1.y
+    }
+
+    /// All points covered by the vent, including both ends
+    fn points(&self) -> impl Iterator<Item = Point> {
+        let horizontal_step = (self.1.x - self.0.x).signum();
+        let vertical_step = (self.1.y - self.0.y).signum();
+        let len = self.0.chebyshev(&self.1);
+        let start = self.0;
+
+        (0..=len).map(move |i| Point {
+            x: start.x + i * horizontal_step,
+            y: start.y + i * vertical_step,
+        })
+    }
+}
+
+impl TryFrom<&str> for Vent {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (start, end) = value
+            .split_once(" -> ")
+            .ok_or(anyhow!("Invalid vent: {}", value))?;
+
+        Ok(Vent(start.parse()?, end.parse()?))
+    }
+}
+
+/// Number of points where at least two vents overlap
+fn overlaps<'a>(vents: impl Iterator<Item = &'a Vent>) -> usize {
+    let mut counts = HashMap::<Point, u32>::new();
+    for p in vents.flat_map(Vent::points) {
+        *counts.entry(p).or_insert(0) += 1;
+    }
+
+    counts.values().filter(|&&x| x > 1).count()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Vec<Vent>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(Vent::try_from).collect()
+    }
+
+    fn part1(vents: &Self::Input) -> Result<usize> {
+        Ok(overlaps(vents.iter().filter(|v| v.is_axis_aligned())))
+    }
+
+    fn part2(vents: &Self::Input) -> Result<usize> {
+        Ok(overlaps(vents.iter()))
+    }
+}
+
+aoc_solution::expected_answers!(Day05);
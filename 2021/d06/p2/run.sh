@@ -1 +0,0 @@
-cd lanternfish && cargo run -- ../../input.txt 256
@@ -0,0 +1,45 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_solution::Solution;
+
+/// Number of fish at each timer value
+pub type School = [u64; 9];
+
+fn simulate(school: &School, days: usize) -> u64 {
+    let mut fish = *school;
+
+    for _ in 0..days {
+        fish.rotate_left(1);
+        fish[6] += fish[8];
+    }
+
+    fish.iter().sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = School;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut fish: School = [0; 9];
+
+        for f in input.trim().split(',') {
+            let f: usize = f.parse().context("Invalid fish")?;
+            *fish.get_mut(f).ok_or(anyhow!("Invalid timer: {}", f))? += 1;
+        }
+
+        Ok(fish)
+    }
+
+    fn part1(school: &Self::Input) -> Result<u64> {
+        Ok(simulate(school, 80))
+    }
+
+    fn part2(school: &Self::Input) -> Result<u64> {
+        Ok(simulate(school, 256))
+    }
+}
+
+aoc_solution::expected_answers!(Day06);
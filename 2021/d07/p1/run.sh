@@ -1 +0,0 @@
-cd whale && cargo run -- ../../input.txt
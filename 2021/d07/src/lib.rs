@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_solution::Solution;
+
+/// Number of crabs at each horizontal position
+pub type Crabs = HashMap<i32, i32>;
+
+/// Cheapest total fuel cost to align all crabs, given the cost of moving a single crab a distance
+fn min_cost(crabs: &Crabs, cost: impl Fn(i32) -> i32) -> Result<i32> {
+    let min = *crabs.keys().min().ok_or(anyhow!("No crabs"))?;
+    let max = *crabs.keys().max().ok_or(anyhow!("No crabs"))?;
+
+    (min..=max)
+        .map(|pos| {
+            crabs
+                .iter()
+                .map(|(p, n)| cost(i32::abs(pos - p)) * n)
+                .sum::<i32>()
+        })
+        .min()
+        .ok_or(anyhow!("No crabs"))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Crabs;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut crabs = Crabs::new();
+
+        for c in input.trim().split(',') {
+            *crabs.entry(c.parse().context("Invalid crab")?).or_insert(0) += 1;
+        }
+
+        Ok(crabs)
+    }
+
+    fn part1(crabs: &Self::Input) -> Result<i32> {
+        min_cost(crabs, |d| d)
+    }
+
+    fn part2(crabs: &Self::Input) -> Result<i32> {
+        min_cost(crabs, |d| d * (d + 1) / 2)
+    }
+}
+
+aoc_solution::expected_answers!(Day07);
@@ -1 +0,0 @@
-cd sss && cargo run -- ../../input.txt
@@ -0,0 +1,47 @@
+use anyhow::{anyhow, Result};
+use aoc_solution::Solution;
+
+/// Segment counts of the digits 1, 7, 4 and 8, the only digits with a unique number of segments
+const UNIQUE_SEG_COUNTS: [usize; 4] = [2, 3, 4, 7];
+
+pub struct Entry {
+    /// Four-digit output value, as scrambled segment patterns
+    output: Vec<String>,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Entry>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let (_, output) = line
+                    .split_once(" | ")
+                    .ok_or(anyhow!("Invalid entry: {}", line))?;
+
+                Ok(Entry {
+                    output: output.split(' ').map(String::from).collect(),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(entries: &Self::Input) -> Result<usize> {
+        Ok(entries
+            .iter()
+            .flat_map(|e| e.output.iter())
+            .filter(|seg| UNIQUE_SEG_COUNTS.contains(&seg.len()))
+            .count())
+    }
+
+    fn part2(_: &Self::Input) -> Result<String> {
+        Err(anyhow!("Part 2 not solved"))
+    }
+}
+
+aoc_solution::expected_answers!(Day08);
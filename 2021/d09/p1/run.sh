@@ -1 +0,0 @@
-cd smoke && cargo run -- ../../input.txt
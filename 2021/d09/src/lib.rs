@@ -0,0 +1,57 @@
+use anyhow::{anyhow, Context, Result};
+use aoc_solution::Solution;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Vec<u32>>;
+    type Part1 = u32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.to_digit(10).context("Invalid height"))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(map: &Self::Input) -> Result<u32> {
+        let height = map.len();
+        let width = map.first().map_or(0, Vec::len);
+        let mut sum = 0;
+
+        for i in 0..height {
+            for j in 0..width {
+                let val = map[i][j];
+                let up = if i > 0 { map[i - 1][j] } else { u32::MAX };
+                let down = if i < (height - 1) {
+                    map[i + 1][j]
+                } else {
+                    u32::MAX
+                };
+                let left = if j > 0 { map[i][j - 1] } else { u32::MAX };
+                let right = if j < (width - 1) {
+                    map[i][j + 1]
+                } else {
+                    u32::MAX
+                };
+
+                if val < up && val < down && val < left && val < right {
+                    sum += val + 1;
+                }
+            }
+        }
+
+        Ok(sum)
+    }
+
+    fn part2(_: &Self::Input) -> Result<String> {
+        Err(anyhow!("Part 2 not solved"))
+    }
+}
+
+aoc_solution::expected_answers!(Day09);
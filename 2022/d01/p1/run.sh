@@ -1 +0,0 @@
-cd calorie && cargo run -- ../../input.txt
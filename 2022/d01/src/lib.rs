@@ -0,0 +1,37 @@
+use anyhow::{Context, Result};
+use aoc_solution::Solution;
+use itertools::Itertools;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// Total calories carried by each elf
+    type Input = Vec<u32>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .group_by(|x| !x.is_empty())
+            .into_iter()
+            .filter_map(|(key, group)| {
+                key.then(|| {
+                    group
+                        .map(|x| x.parse::<u32>().context("Invalid calories"))
+                        .sum::<Result<u32>>()
+                })
+            })
+            .collect()
+    }
+
+    fn part1(elves: &Self::Input) -> Result<u32> {
+        Ok(elves.iter().max().copied().unwrap_or(0))
+    }
+
+    fn part2(elves: &Self::Input) -> Result<u32> {
+        Ok(elves.iter().sorted().rev().take(3).sum())
+    }
+}
+
+aoc_solution::expected_answers!(Day01);
@@ -1 +0,0 @@
-cd rps && cargo run -- ../../input.txt
@@ -1,8 +1,5 @@
-use std::convert::TryFrom;
-use std::env;
-use std::error::Error;
-use std::fs::File;
-use std::io::{self, BufRead};
+use anyhow::{Error, Result};
+use aoc_solution::Solution;
 
 enum Outcome {
     Win,
@@ -48,6 +45,20 @@ impl Shape {
         }
     }
 
+    fn play(&self, other: &Shape) -> Outcome {
+        match (self, other) {
+            (Shape::Rock, Shape::Scissors)
+            | (Shape::Paper, Shape::Rock)
+            | (Shape::Scissors, Shape::Paper) => Outcome::Win,
+
+            (Shape::Rock, Shape::Paper)
+            | (Shape::Paper, Shape::Scissors)
+            | (Shape::Scissors, Shape::Rock) => Outcome::Loss,
+
+            _ => Outcome::Draw,
+        }
+    }
+
     fn response(&self, outcome: &Outcome) -> Shape {
         match (self, outcome) {
             (Shape::Rock, Outcome::Win)
@@ -68,29 +79,37 @@ impl TryFrom<char> for Shape {
 
     fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            'A' => Ok(Shape::Rock),
-            'B' => Ok(Shape::Paper),
-            'C' => Ok(Shape::Scissors),
+            'A' | 'X' => Ok(Shape::Rock),
+            'B' | 'Y' => Ok(Shape::Paper),
+            'C' | 'Z' => Ok(Shape::Scissors),
             _ => Err(format!("Invalid shape input: {}", value)),
         }
     }
 }
 
-struct Round {
+
+/// A round of the strategy guide. The second column is either the shape to
+/// respond with or the desired outcome, depending on how the guide is read
+pub struct Round {
     opponent: Shape,
+    response: Shape,
     outcome: Outcome,
 }
 
 impl Round {
-    fn score(&self) -> u32 {
+    fn score_as_response(&self) -> u32 {
+        self.response.score() + self.response.play(&self.opponent).score()
+    }
+
+    fn score_as_outcome(&self) -> u32 {
         self.opponent.response(&self.outcome).score() + self.outcome.score()
     }
 }
 
-impl TryFrom<String> for Round {
+impl TryFrom<&str> for Round {
     type Error = String;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
         let mut chars = value.chars();
 
         let (opponent, response) = (
@@ -98,33 +117,39 @@ impl TryFrom<String> for Round {
                 .next()
                 .ok_or(format!("Invalid round input: {}", value))?,
             chars
-                .skip(1) // skip space
-                .next()
+                .nth(1)
                 .ok_or(format!("Invalid round input: {}", value))?,
         );
 
         Ok(Round {
             opponent: opponent.try_into()?,
+            response: response.try_into()?,
             outcome: response.try_into()?,
         })
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = env::args().collect();
-    let input = File::open(&args[1])?;
-    let reader = io::BufReader::new(input);
-
-    let score = &reader
-        .lines()
-        .filter_map(|line| {
-            line.ok()
-                .and_then(|line| line.try_into().ok())
-                .and_then(|r: Round| Some(r.score()))
-        })
-        .sum::<u32>();
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Round>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.try_into().map_err(Error::msg))
+            .collect()
+    }
 
-    println!("{}", score);
+    fn part1(rounds: &Self::Input) -> Result<u32> {
+        Ok(rounds.iter().map(Round::score_as_response).sum())
+    }
 
-    Ok(())
+    fn part2(rounds: &Self::Input) -> Result<u32> {
+        Ok(rounds.iter().map(Round::score_as_outcome).sum())
+    }
 }
+
+aoc_solution::expected_answers!(Day02);
@@ -1 +0,0 @@
-cd rucksack && cargo run -- ../../input.txt
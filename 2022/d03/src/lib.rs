@@ -0,0 +1,101 @@
+use std::collections::BTreeSet;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_solution::Solution;
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Item {
+    priority: u32,
+}
+
+impl Item {
+    const LOWERCASE_OFFSET: u32 = ('a' as u32) - 1;
+    const UPPERCASE_OFFSET: u32 = ('A' as u32) - 1 - 26;
+}
+
+impl TryFrom<char> for Item {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'a'..='z' => Ok(Self {
+                priority: (value as u32) - Self::LOWERCASE_OFFSET,
+            }),
+            'A'..='Z' => Ok(Self {
+                priority: (value as u32) - Self::UPPERCASE_OFFSET,
+            }),
+            _ => Err(format!("Invalid item input: {}", value)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Rucksack {
+    contents: Vec<Item>,
+}
+
+impl Rucksack {
+    fn dup(&self) -> Item {
+        let mid = self.contents.len() / 2;
+        let first_half: BTreeSet<&Item> = self.contents[..mid].iter().collect();
+        let second_half: BTreeSet<&Item> = self.contents[mid..].iter().collect();
+        **first_half.intersection(&second_half).next().unwrap()
+    }
+
+    fn common(&self, other: &Rucksack) -> Rucksack {
+        let mine: BTreeSet<&Item> = self.contents.iter().collect();
+        let theirs: BTreeSet<&Item> = other.contents.iter().collect();
+        Rucksack {
+            contents: mine.intersection(&theirs).cloned().cloned().collect(),
+        }
+    }
+}
+
+impl TryFrom<&str> for Rucksack {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value
+            .chars()
+            .map(|x| x.try_into())
+            .collect::<Result<Vec<Item>, _>>()
+            .map(|contents| Rucksack { contents })
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Rucksack>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.try_into().map_err(Error::msg))
+            .collect()
+    }
+
+    fn part1(rucksacks: &Self::Input) -> Result<u32> {
+        Ok(rucksacks.iter().map(|r| r.dup().priority).sum())
+    }
+
+    fn part2(rucksacks: &Self::Input) -> Result<u32> {
+        rucksacks
+            .chunks(3)
+            .map(|group| {
+                group
+                    .iter()
+                    .skip(1)
+                    .fold(group[0].clone(), |acc, r| r.common(&acc))
+                    .contents
+                    .pop()
+                    .map(|badge| badge.priority)
+                    .ok_or(anyhow!("No common item in group"))
+            })
+            .sum()
+    }
+}
+
+aoc_solution::expected_answers!(Day03);
@@ -1 +0,0 @@
-cd cleanup && cargo run -- ../../input.txt
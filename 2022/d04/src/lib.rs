@@ -0,0 +1,53 @@
+use anyhow::{Error, Result};
+use aoc_interval::Interval;
+use aoc_solution::Solution;
+
+/// Parse the inclusive range of section IDs assigned to an elf
+fn parse_assignment(value: &str) -> Result<Interval<u32>, String> {
+    value
+        .split_once('-')
+        .and_then(|(start, end)| Some(Interval::inclusive(start.parse().ok()?, end.parse().ok()?)))
+        .ok_or(format!("Invalid assignment input: {}", value))
+}
+
+pub struct Pair(Interval<u32>, Interval<u32>);
+
+impl TryFrom<&str> for Pair {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (first, second) = value
+            .split_once(',')
+            .ok_or(format!("Invalid pair input: {}", value))?;
+
+        Ok(Pair(parse_assignment(first)?, parse_assignment(second)?))
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.try_into().map_err(Error::msg))
+            .collect()
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<usize> {
+        Ok(pairs
+            .iter()
+            .filter(|p| p.0.contains_interval(&p.1) || p.1.contains_interval(&p.0))
+            .count())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<usize> {
+        Ok(pairs.iter().filter(|p| p.0.overlaps(&p.1)).count())
+    }
+}
+
+aoc_solution::expected_answers!(Day04);
@@ -1 +0,0 @@
-cd supply && cargo run -- ../../input.txt
@@ -0,0 +1,136 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use anyhow::{Error, Result};
+use aoc_solution::Solution;
+
+pub struct Instruction {
+    source: char,
+    dest: char,
+    amount: usize,
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let parts: Vec<&str> = value.split_whitespace().collect();
+
+        Ok(Instruction {
+            source: parts[3].chars().next().unwrap(),
+            dest: parts[5].chars().next().unwrap(),
+            amount: parts[1]
+                .parse()
+                .map_err(|_| format!("Invalid instruction input: {}", value))?,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct Stacks {
+    stacks: HashMap<char, RefCell<Vec<char>>>,
+    labels: Vec<char>,
+}
+
+impl Stacks {
+    /// Move crates between stacks, either one at a time (reversing their
+    /// order) or all at once
+    fn update(&self, instruction: &Instruction, one_at_a_time: bool) {
+        let mut source = self.stacks[&instruction.source].borrow_mut();
+        let mut dest = self.stacks[&instruction.dest].borrow_mut();
+        let offset = source.len() - instruction.amount;
+        if one_at_a_time {
+            dest.extend(source.drain(offset..).rev())
+        } else {
+            dest.extend(source.drain(offset..))
+        }
+    }
+
+    fn tops(&self) -> String {
+        self.labels
+            .iter()
+            .map(|label| *self.stacks[label].borrow().last().unwrap())
+            .collect()
+    }
+}
+
+impl TryFrom<Vec<String>> for Stacks {
+    type Error = String;
+
+    fn try_from(mut value: Vec<String>) -> Result<Self, Self::Error> {
+        let raw_labels = value.pop().unwrap();
+        let labels: Vec<char> = raw_labels
+            .split_whitespace()
+            .map(|x| x.chars().next().unwrap())
+            .collect();
+
+        let mut stacks: HashMap<char, RefCell<Vec<char>>> = HashMap::new();
+        for level in value.iter().rev() {
+            for (i, label) in level
+                .chars()
+                .skip(1)
+                .step_by(4)
+                .enumerate()
+                .filter(|&(_, c)| c != ' ')
+            {
+                stacks.entry(labels[i]).or_default().get_mut().push(label)
+            }
+        }
+
+        Ok(Stacks { stacks, labels })
+    }
+}
+
+pub struct Procedure {
+    stacks: Stacks,
+    instructions: Vec<Instruction>,
+}
+
+impl Procedure {
+    fn rearrange(&self, one_at_a_time: bool) -> String {
+        let stacks = self.stacks.clone();
+        for i in self.instructions.iter() {
+            stacks.update(i, one_at_a_time);
+        }
+
+        stacks.tops()
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Procedure;
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines = &mut input.lines();
+        let stacks: Stacks = lines
+            .by_ref()
+            .take_while(|line| !line.is_empty())
+            .map(String::from)
+            .collect::<Vec<String>>()
+            .try_into()
+            .map_err(Error::msg)?;
+
+        let instructions = lines
+            .map(|line| line.try_into().map_err(Error::msg))
+            .collect::<Result<_>>()?;
+
+        Ok(Procedure {
+            stacks,
+            instructions,
+        })
+    }
+
+    fn part1(procedure: &Self::Input) -> Result<String> {
+        Ok(procedure.rearrange(true))
+    }
+
+    fn part2(procedure: &Self::Input) -> Result<String> {
+        Ok(procedure.rearrange(false))
+    }
+}
+
+aoc_solution::expected_answers!(Day05);
@@ -1 +0,0 @@
-cd tuning && cargo run -- ../../input.txt 4
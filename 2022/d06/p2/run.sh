@@ -1 +0,0 @@
-cd ../p1/tuning && cargo run -- ../../input.txt 14
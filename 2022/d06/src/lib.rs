@@ -1,8 +1,10 @@
 use std::collections::{HashMap, VecDeque};
-use std::env;
-use std::error::Error;
-use std::fs::File;
-use std::io::{self, Read};
+
+use anyhow::Result;
+use aoc_solution::Solution;
+
+const START_OF_PACKET_LEN: usize = 4;
+const START_OF_MESSAGE_LEN: usize = 14;
 
 struct SignalBuf {
     buf: VecDeque<u8>,
@@ -12,9 +14,14 @@ struct SignalBuf {
 
 impl SignalBuf {
     fn new(init: Vec<u8>) -> SignalBuf {
+        let mut counter = HashMap::new();
+        for c in init.iter() {
+            *counter.entry(*c).or_insert(0) += 1;
+        }
+
         SignalBuf {
             buf: VecDeque::from_iter(init.iter().cloned()),
-            counter: HashMap::from_iter(init.iter().cloned().map(|c| (c, 1))),
+            counter,
             bytes_read: init.len(),
         }
     }
@@ -49,13 +56,9 @@ impl SignalBuf {
     }
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let args: Vec<String> = env::args().collect();
-    let input = File::open(&args[1])?;
-    let marker_len: usize = args[2].parse()?;
-
-    let reader = io::BufReader::new(input);
-    let chars = &mut reader.bytes().filter_map(|x| x.ok());
+/// Number of characters read up to and including the first marker of `marker_len` unique characters
+fn find_marker(signal: &str, marker_len: usize) -> usize {
+    let chars = &mut signal.bytes();
 
     let init = chars.by_ref().take(marker_len).collect::<Vec<u8>>();
     let mut buf = SignalBuf::new(init);
@@ -65,7 +68,27 @@ fn main() -> Result<(), Box<dyn Error>> {
         buf.read(c);
     }
 
-    println!("{}", buf.bytes_read);
+    buf.bytes_read
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(signal: &Self::Input) -> Result<usize> {
+        Ok(find_marker(signal, START_OF_PACKET_LEN))
+    }
 
-    Ok(())
+    fn part2(signal: &Self::Input) -> Result<usize> {
+        Ok(find_marker(signal, START_OF_MESSAGE_LEN))
+    }
 }
+
+aoc_solution::expected_answers!(Day06);
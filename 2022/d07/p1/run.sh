@@ -1 +0,0 @@
-cd space && cargo run -- ../../input.txt
@@ -0,0 +1,196 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Index of a file or directory in a FileSystem
+pub type NodeId = usize;
+
+#[derive(Debug)]
+pub enum Kind {
+    Dir {
+        children: BTreeMap<String, NodeId>,
+        /// Whether the directory's contents have been listed
+        listed: bool,
+    },
+    File(usize),
+}
+
+#[derive(Debug)]
+pub struct Node {
+    pub name: String,
+    pub parent: Option<NodeId>,
+    pub kind: Kind,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, Kind::Dir { .. })
+    }
+}
+
+/// Tree of directories and files, stored as an arena where every node comes
+/// after its parent
+#[derive(Debug)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node {
+                name: "/".into(),
+                parent: None,
+                kind: Kind::Dir {
+                    children: BTreeMap::new(),
+                    listed: false,
+                },
+            }],
+        }
+    }
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = 0;
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id].parent
+    }
+
+    fn children(&self, id: NodeId) -> Option<&BTreeMap<String, NodeId>> {
+        match &self.nodes[id].kind {
+            Kind::Dir { children, .. } => Some(children),
+            Kind::File(_) => None,
+        }
+    }
+
+    pub fn child(&self, dir: NodeId, name: &str) -> Option<NodeId> {
+        self.children(dir)?.get(name).copied()
+    }
+
+    /// Add an entry to a directory, or check it matches the entry already there
+    fn add(&mut self, dir: NodeId, name: &str, kind: Kind) -> Result<NodeId, String> {
+        if let Some(existing) = self.child(dir, name) {
+            return match (&self.nodes[existing].kind, &kind) {
+                (Kind::Dir { .. }, Kind::Dir { .. }) => Ok(existing),
+                (Kind::File(a), Kind::File(b)) if a == b => Ok(existing),
+                _ => Err(format!("Conflicting entries for {}", self.path(existing))),
+            };
+        }
+
+        let id = self.nodes.len();
+        match &mut self.nodes[dir].kind {
+            Kind::Dir { children, .. } => children.insert(name.into(), id),
+            Kind::File(_) => return Err(format!("{} is not a directory", self.path(dir))),
+        };
+        self.nodes.push(Node {
+            name: name.into(),
+            parent: Some(dir),
+            kind,
+        });
+
+        Ok(id)
+    }
+
+    pub fn mkdir(&mut self, dir: NodeId, name: &str) -> Result<NodeId, String> {
+        let kind = Kind::Dir {
+            children: BTreeMap::new(),
+            listed: false,
+        };
+        self.add(dir, name, kind)
+    }
+
+    pub fn touch(&mut self, dir: NodeId, name: &str, size: usize) -> Result<NodeId, String> {
+        self.add(dir, name, Kind::File(size))
+    }
+
+    /// Mark a directory's contents as listed, returning whether they already were
+    pub fn mark_listed(&mut self, dir: NodeId) -> bool {
+        match &mut self.nodes[dir].kind {
+            Kind::Dir { listed, .. } => std::mem::replace(listed, true),
+            Kind::File(_) => false,
+        }
+    }
+
+    /// Absolute path of a node, e.g. `/a/e`
+    pub fn path(&self, id: NodeId) -> String {
+        let mut names = Vec::new();
+        let mut node = Some(id);
+        while let Some(n) = node.filter(|&n| n != Self::ROOT) {
+            names.push(self.nodes[n].name.as_str());
+            node = self.parent(n);
+        }
+        names.reverse();
+
+        format!("/{}", names.join("/"))
+    }
+
+    /// Find a node by absolute path
+    pub fn lookup(&self, path: &str) -> Option<NodeId> {
+        path.split('/')
+            .filter(|name| !name.is_empty())
+            .try_fold(Self::ROOT, |dir, name| self.child(dir, name))
+    }
+
+    /// Total size of every node, including everything inside directories
+    pub fn sizes(&self) -> Vec<usize> {
+        let mut sizes = self
+            .nodes
+            .iter()
+            .map(|n| match n.kind {
+                Kind::File(size) => size,
+                Kind::Dir { .. } => 0,
+            })
+            .collect::<Vec<_>>();
+
+        // children always come after their parents
+        for id in (1..self.nodes.len()).rev() {
+            if let Some(parent) = self.parent(id) {
+                sizes[parent] += sizes[id];
+            }
+        }
+
+        sizes
+    }
+
+    /// Total size of a file or directory, like `du -s`
+    pub fn du(&self, id: NodeId) -> usize {
+        match &self.nodes[id].kind {
+            Kind::File(size) => *size,
+            Kind::Dir { children, .. } => children.values().map(|&c| self.du(c)).sum(),
+        }
+    }
+
+    /// Every directory whose total size matches the predicate, with its size
+    pub fn find_dirs(&self, predicate: impl Fn(usize) -> bool) -> Vec<(NodeId, usize)> {
+        self.sizes()
+            .into_iter()
+            .enumerate()
+            .filter(|&(id, size)| self.nodes[id].is_dir() && predicate(size))
+            .collect()
+    }
+
+    fn fmt_node(&self, f: &mut fmt::Formatter, id: NodeId, depth: usize) -> fmt::Result {
+        let node = &self.nodes[id];
+        write!(f, "{:indent$}- {} ", "", node.name, indent = depth * 2)?;
+        match &node.kind {
+            Kind::File(size) => writeln!(f, "(file, size={size})"),
+            Kind::Dir { children, .. } => {
+                writeln!(f, "(dir)")?;
+                children
+                    .values()
+                    .try_for_each(|&c| self.fmt_node(f, c, depth + 1))
+            }
+        }
+    }
+}
+
+/// Print the tree like `tree`, in the puzzle's format
+impl fmt::Display for FileSystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_node(f, Self::ROOT, 0)
+    }
+}
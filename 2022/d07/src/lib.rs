@@ -0,0 +1,226 @@
+use anyhow::{anyhow, Error, Result};
+use aoc_solution::Solution;
+
+mod fs;
+
+pub use fs::{FileSystem, Kind, Node, NodeId};
+
+const THRESHOLD: usize = 100_000;
+const TOTAL_SPACE: usize = 70_000_000;
+const DESIRED_SPACE: usize = 30_000_000;
+
+pub enum Output {
+    Dir(String),
+    File(String, usize),
+}
+
+impl TryFrom<String> for Output {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let mut parts = value.split_whitespace();
+        match parts.next().unwrap() {
+            "dir" => Ok(Output::Dir(parts.next().unwrap().into())),
+            size => Ok(Output::File(
+                parts.next().unwrap().into(),
+                size.parse()
+                    .map_err(|_| format!("Invalid output: {}", value))?,
+            )),
+        }
+    }
+}
+
+struct ChangeDir {
+    dir: String,
+}
+
+impl ChangeDir {
+    const ROOT: &str = "/";
+    const UP: &str = "..";
+}
+
+#[derive(Default)]
+struct ListDir {
+    output: Vec<Output>,
+}
+
+enum Command {
+    ChangeDir(ChangeDir),
+    ListDir(ListDir),
+}
+
+impl TryFrom<String> for Command {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.starts_with("$") {
+            let mut parts = value.split_whitespace().skip(1);
+            match parts.next().unwrap() {
+                "cd" => Ok(Command::ChangeDir(ChangeDir {
+                    dir: parts.next().unwrap().into(),
+                })),
+                "ls" => Ok(Command::ListDir(Default::default())),
+                _ => Err(format!("Invalid command: {}", value)),
+            }
+        } else {
+            Err(format!("Invalid command: {}", value))
+        }
+    }
+}
+
+/// Replays a terminal session to rebuild the file system it explored
+#[derive(Default)]
+pub struct Terminal {
+    fs: FileSystem,
+    cwd: NodeId,
+    /// Directories listed more than once
+    relisted: Vec<NodeId>,
+}
+
+impl Terminal {
+    fn process(&mut self, cmd: &Command) -> Result<(), String> {
+        match cmd {
+            Command::ChangeDir(cd) => {
+                self.cwd = match cd.dir.as_str() {
+                    ChangeDir::ROOT => FileSystem::ROOT,
+                    ChangeDir::UP => self
+                        .fs
+                        .parent(self.cwd)
+                        .ok_or("Can't go up from /".to_string())?,
+                    dir => self.fs.mkdir(self.cwd, dir)?,
+                }
+            }
+            Command::ListDir(ls) => {
+                if self.fs.mark_listed(self.cwd) {
+                    self.relisted.push(self.cwd);
+                }
+
+                // entries listed again must match, so they're never counted twice
+                for o in &ls.output {
+                    match o {
+                        Output::Dir(name) => self.fs.mkdir(self.cwd, name)?,
+                        Output::File(name, size) => self.fs.touch(self.cwd, name, *size)?,
+                    };
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn fs(&self) -> &FileSystem {
+        &self.fs
+    }
+
+    /// Paths of directories that were listed more than once
+    pub fn relisted(&self) -> impl Iterator<Item = String> + '_ {
+        self.relisted.iter().map(|&dir| self.fs.path(dir))
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Terminal;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut commands: Vec<Command> = Vec::new();
+
+        for line in input.lines().map(String::from) {
+            match line.clone().try_into() {
+                Ok(cmd) => commands.push(cmd),
+                Err(_) => match commands.last_mut() {
+                    Some(Command::ListDir(c)) => {
+                        c.output.push(line.try_into().map_err(Error::msg)?);
+                    }
+                    _ => return Err(anyhow!("Output outside of ls: {}", line)),
+                },
+            }
+        }
+
+        let mut term: Terminal = Default::default();
+        for cmd in commands.iter() {
+            term.process(cmd).map_err(Error::msg)?;
+        }
+
+        Ok(term)
+    }
+
+    fn part1(term: &Self::Input) -> Result<usize> {
+        Ok(term
+            .fs()
+            .find_dirs(|size| size <= THRESHOLD)
+            .into_iter()
+            .map(|(_, size)| size)
+            .sum())
+    }
+
+    fn part2(term: &Self::Input) -> Result<usize> {
+        let used = term.fs().sizes()[FileSystem::ROOT];
+        let free_space = TOTAL_SPACE
+            .checked_sub(used)
+            .ok_or_else(|| anyhow!("Files take up {used}, more than the disk's {TOTAL_SPACE}"))?;
+        let threshold = DESIRED_SPACE.saturating_sub(free_space);
+
+        term.fs()
+            .find_dirs(|size| size >= threshold)
+            .into_iter()
+            .map(|(_, size)| size)
+            .min()
+            .ok_or(anyhow!("No directory large enough"))
+    }
+}
+
+aoc_solution::expected_answers!(Day07);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn tree() {
+        let term = Day07::parse(EXAMPLE).unwrap();
+        let fs = term.fs();
+
+        assert!(fs.to_string().starts_with(
+            "- / (dir)\n  - a (dir)\n    - e (dir)\n      - i (file, size=584)\n    - f (file, size=29116)\n"
+        ));
+        assert_eq!(fs.du(fs.lookup("/a/e").unwrap()), 584);
+        assert_eq!(fs.path(fs.lookup("/d/k").unwrap()), "/d/k");
+        assert_eq!(fs.lookup("/a/x"), None);
+
+        let small = fs
+            .find_dirs(|size| size <= 100_000)
+            .into_iter()
+            .map(|(id, _)| fs.path(id))
+            .collect::<Vec<_>>();
+        assert_eq!(small, ["/a", "/a/e"]);
+    }
+
+    #[test]
+    fn relisted() {
+        let input = format!(
+            "{}\n$ cd /\n$ ls\ndir a\n14848514 b.txt\n",
+            EXAMPLE.trim_end()
+        );
+        let term = Day07::parse(&input).unwrap();
+        assert_eq!(term.relisted().collect::<Vec<_>>(), ["/"]);
+        assert_eq!(Day07::part1(&term).unwrap(), 95437);
+
+        let conflicting = format!("{}\n$ cd /\n$ ls\n1 b.txt\n", EXAMPLE.trim_end());
+        assert!(Day07::parse(&conflicting).is_err());
+    }
+
+    #[test]
+    fn overfull() {
+        let term = Day07::parse("$ cd /\n$ ls\n80000000 big.txt\n").unwrap();
+        assert_eq!(
+            Day07::part2(&term).unwrap_err().to_string(),
+            "Files take up 80000000, more than the disk's 70000000"
+        );
+    }
+}
@@ -1 +0,0 @@
-cd treetop && cargo run -- ../../input.txt
@@ -0,0 +1,241 @@
+use anyhow::{anyhow, Error, Result};
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+use itertools::Either;
+
+pub struct ScenicScore {
+    pub north: u32,
+    pub south: u32,
+    pub east: u32,
+    pub west: u32,
+}
+
+impl ScenicScore {
+    fn score(&self) -> u32 {
+        self.north * self.south * self.east * self.west
+    }
+}
+
+pub struct Tree {
+    pub height: u32,
+    pub visible: bool,
+    pub scenic_score: u32,
+}
+
+impl TryFrom<char> for Tree {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let height = value
+            .to_digit(10)
+            .ok_or(format!("Invalid tree height: {}", value))?;
+
+        Ok(Tree {
+            height,
+            visible: false,
+            scenic_score: 0,
+        })
+    }
+}
+
+pub struct TreeGrid {
+    pub trees: Grid<Tree>,
+    pub side_len: usize,
+    pub num_visible: usize,
+}
+
+impl TreeGrid {
+    pub fn new(trees: Grid<Tree>) -> Result<TreeGrid, String> {
+        let side_len = trees.rows();
+
+        if trees.cols() != side_len {
+            Err(format!(
+                "Tree grid ({}x{}) is not square",
+                trees.rows(),
+                trees.cols()
+            ))
+        } else {
+            let mut grid = TreeGrid {
+                trees,
+                side_len,
+                num_visible: 0,
+            };
+
+            grid.mark_visibility();
+            grid.score_trees();
+
+            Ok(grid)
+        }
+    }
+
+    fn interior(&self) -> impl DoubleEndedIterator<Item = usize> {
+        // iterator over interior rows/cols
+        1..self.side_len - 1
+    }
+
+    pub fn tree(&self, row: usize, col: usize) -> &Tree {
+        &self.trees[(row, col)]
+    }
+
+    fn tree_mut(&mut self, row: usize, col: usize) -> &mut Tree {
+        &mut self.trees[(row, col)]
+    }
+
+    fn mark_visibility(&mut self) {
+        // edge trees are always visible
+        self.num_visible = (self.side_len - 1) * 4;
+        for row in 0..self.side_len {
+            let cols = if row == 0 || row == self.side_len - 1 {
+                Either::Left(0..self.side_len)
+            } else {
+                Either::Right([0, self.side_len - 1].into_iter())
+            };
+            for col in cols {
+                self.tree_mut(row, col).visible = true;
+            }
+        }
+
+        // set north/west invisibility
+        let mut max_vs: Vec<u32> = self
+            .interior()
+            .map(|col| self.tree(0, col).height)
+            .collect();
+
+        for row in self.interior() {
+            let mut max_h: u32 = self.tree(row, 0).height;
+            for col in self.interior() {
+                let tree = self.tree_mut(row, col);
+
+                if tree.height > max_h {
+                    max_h = tree.height;
+                    tree.visible = true;
+                }
+
+                let max_v = &mut max_vs[col - 1];
+                if tree.height > *max_v {
+                    *max_v = tree.height;
+                    tree.visible = true;
+                }
+            }
+        }
+
+        // set south/east invisibility and count
+        max_vs = self
+            .interior()
+            .map(|col| self.tree(self.side_len - 1, col).height)
+            .collect();
+
+        for row in self.interior().rev() {
+            let mut max_h: u32 = self.tree(row, self.side_len - 1).height;
+            for col in self.interior().rev() {
+                let tree = self.tree_mut(row, col);
+
+                if tree.height > max_h {
+                    max_h = tree.height;
+                    tree.visible = true;
+                }
+
+                let max_v = &mut max_vs[col - 1];
+                if tree.height > *max_v {
+                    *max_v = tree.height;
+                    tree.visible = true;
+                }
+
+                if tree.visible {
+                    self.num_visible += 1;
+                }
+            }
+        }
+    }
+
+    fn score_trees(&mut self) {
+        // edge trees are always 0 so can be ignored
+        for row in self.interior() {
+            for col in self.interior() {
+                self.score(row, col)
+            }
+        }
+    }
+
+    fn score(&mut self, row: usize, col: usize) {
+        let height = self.tree(row, col).height;
+
+        // initialize to highest possible scores
+        let mut score = ScenicScore {
+            north: row as u32,
+            south: (self.side_len - 1 - row) as u32,
+            east: (self.side_len - 1 - col) as u32,
+            west: col as u32,
+        };
+
+        // north
+        for r in (0..row).rev() {
+            if self.tree(r, col).height >= height {
+                score.north = (row - r) as u32;
+                break;
+            }
+        }
+
+        // south
+        for r in (row..self.side_len).skip(1) {
+            if self.tree(r, col).height >= height {
+                score.south = (r - row) as u32;
+                break;
+            }
+        }
+
+        // east
+        for c in (col..self.side_len).skip(1) {
+            if self.tree(row, c).height >= height {
+                score.east = (c - col) as u32;
+                break;
+            }
+        }
+
+        // west
+        for c in (0..col).rev() {
+            if self.tree(row, c).height >= height {
+                score.west = (col - c) as u32;
+                break;
+            }
+        }
+
+        self.tree_mut(row, col).scenic_score = score.score();
+    }
+}
+
+impl TryFrom<&str> for TreeGrid {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let trees = Grid::parse(value).map_err(|e| format!("{:#}", e))?;
+
+        TreeGrid::new(trees)
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = TreeGrid;
+    type Part1 = usize;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.try_into().map_err(Error::msg)
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        Ok(grid.num_visible)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<u32> {
+        grid.trees
+            .iter()
+            .map(|t| t.scenic_score)
+            .max()
+            .ok_or(anyhow!("No trees"))
+    }
+}
+
+aoc_solution::expected_answers!(Day08);
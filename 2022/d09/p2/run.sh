@@ -1 +0,0 @@
-cd bridge && cargo run -- ../../input.txt
@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_geometry::{Direction, Point};
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+use aoc_visualize::{Frame, NoRecorder, Pixel, Recorder, Rgb};
+use itertools::Itertools;
+
+pub struct Motion {
+    pub direction: Direction,
+    pub steps: usize,
+}
+
+impl TryFrom<&str> for Motion {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut parts = value.split_whitespace();
+
+        let direction = parts
+            .next()
+            .and_then(|d| d.chars().exactly_one().ok())
+            .and_then(|c| Direction::try_from(c).ok())
+            .ok_or(format!("Invalid direction input: {}", value))?;
+
+        let steps: usize = parts
+            .next()
+            .and_then(|s| s.parse().ok())
+            .ok_or(format!("Invalid direction input: {}", value))?;
+
+        Ok(Motion { direction, steps })
+    }
+}
+
+pub struct Rope {
+    knots: Vec<Point>,
+    tail_visited: HashSet<Point>,
+}
+
+impl Rope {
+    pub fn new(len: usize) -> Rope {
+        Rope {
+            knots: vec![Point::origin(); len],
+            tail_visited: HashSet::from([Point::origin()]),
+        }
+    }
+
+    pub fn move_head(&mut self, motion: &Motion) {
+        for _ in 0..motion.steps {
+            self.step_head(motion.direction);
+        }
+    }
+
+    pub fn step_head(&mut self, direction: Direction) {
+        self.knots[0] = self.knots[0].step(direction);
+        self.catch_up();
+    }
+
+    fn catch_up(&mut self) {
+        // go through each pair of adjacent knots
+        for i in 0..self.knots.len() - 1 {
+            // if the next knot is still touching, nothing more to do
+            if self.knots[i].chebyshev(&self.knots[i + 1]) <= 1 {
+                break;
+            }
+
+            // catch up
+            if self.knots[i].x == self.knots[i + 1].x {
+                // same col
+                self.knots[i + 1].y += (self.knots[i].y - self.knots[i + 1].y).signum();
+            } else if self.knots[i].y == self.knots[i + 1].y {
+                // same row
+                self.knots[i + 1].x += (self.knots[i].x - self.knots[i + 1].x).signum();
+            } else {
+                // diagonal
+                self.knots[i + 1].x += (self.knots[i].x - self.knots[i + 1].x).signum();
+                self.knots[i + 1].y += (self.knots[i].y - self.knots[i + 1].y).signum();
+            }
+
+            // if next knot is the tail, update visited
+            if i + 1 == self.knots.len() - 1 {
+                self.tail_visited.insert(self.knots[i + 1]);
+            }
+        }
+    }
+
+    pub fn tail_visited(&self) -> usize {
+        self.tail_visited.len()
+    }
+
+    /// Draw the part of the plane between the min and max corners, with cells the
+    /// tail has visited in green and each knot labelled by its index (the head is H)
+    fn frame(&self, (min, max): (Point, Point)) -> Frame {
+        let rows = (max.y - min.y + 1) as usize;
+        let cols = (max.x - min.x + 1) as usize;
+        let mut frame = Grid::filled(rows, cols, Pixel::new('.', Rgb::gray(48)));
+
+        let mut draw = |p: Point, pixel| {
+            let offset = p - min;
+            frame[(offset.y as usize, offset.x as usize)] = pixel;
+        };
+        for &p in &self.tail_visited {
+            draw(p, Pixel::new('#', Rgb::GREEN));
+        }
+        for (i, &p) in self.knots.iter().enumerate().rev() {
+            let glyph = match i {
+                0 => 'H',
+                i => char::from_digit(i as u32, 36).unwrap_or('*'),
+            };
+            draw(p, Pixel::new(glyph, Rgb::WHITE));
+        }
+
+        frame
+    }
+}
+
+/// Min and max corners of everywhere the head goes. The other knots only ever
+/// follow the head, so they stay inside too.
+fn head_bounds(motions: &[Motion]) -> (Point, Point) {
+    let mut head = Point::origin();
+    let (mut min, mut max) = (head, head);
+    for motion in motions {
+        head = head.step_n(motion.direction, motion.steps as isize);
+        min = Point::new(min.x.min(head.x), min.y.min(head.y));
+        max = Point::new(max.x.max(head.x), max.y.max(head.y));
+    }
+
+    (min, max)
+}
+
+const SHORT_ROPE_LEN: usize = 2;
+const LONG_ROPE_LEN: usize = 10;
+
+fn tail_visited(motions: &[Motion], rope_len: usize, recorder: &mut dyn Recorder) -> usize {
+    let bounds = head_bounds(motions);
+
+    let mut rope = Rope::new(rope_len);
+    recorder.draw(|| rope.frame(bounds));
+    for motion in motions {
+        for _ in 0..motion.steps {
+            rope.step_head(motion.direction);
+            recorder.draw(|| rope.frame(bounds));
+        }
+    }
+
+    rope.tail_visited()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Motion>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.try_into().map_err(Error::msg))
+            .collect()
+    }
+
+    fn part1(motions: &Self::Input) -> Result<usize> {
+        Ok(tail_visited(motions, SHORT_ROPE_LEN, &mut NoRecorder))
+    }
+
+    fn part2(motions: &Self::Input) -> Result<usize> {
+        Ok(tail_visited(motions, LONG_ROPE_LEN, &mut NoRecorder))
+    }
+
+    fn visualize(part: u8, motions: &Self::Input, recorder: &mut dyn Recorder) -> Result<()> {
+        let rope_len = match part {
+            1 => SHORT_ROPE_LEN,
+            2 => LONG_ROPE_LEN,
+            _ => return Err(anyhow!("Invalid part: {part}")),
+        };
+
+        tail_visited(motions, rope_len, recorder);
+        Ok(())
+    }
+}
+
+aoc_solution::expected_answers!(Day09);
@@ -1 +0,0 @@
-cd cathode && cargo run -- ../../input.txt
@@ -0,0 +1,202 @@
+use std::fmt::{Debug, Display};
+
+/// The CPU's registers; so far there's only X
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Registers {
+    pub x: i32,
+}
+
+impl Default for Registers {
+    fn default() -> Self {
+        Self { x: 1 }
+    }
+}
+
+/// An instruction takes some number of cycles, and only changes the registers
+/// once its last cycle completes
+pub trait Instruction: Debug + Display {
+    fn cycles(&self) -> usize;
+    fn execute(&self, registers: &mut Registers);
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct NoOp;
+
+impl Instruction for NoOp {
+    fn cycles(&self) -> usize {
+        1
+    }
+
+    fn execute(&self, _registers: &mut Registers) {}
+}
+
+impl Display for NoOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "noop")
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AddX(pub i32);
+
+impl Instruction for AddX {
+    fn cycles(&self) -> usize {
+        2
+    }
+
+    fn execute(&self, registers: &mut Registers) {
+        registers.x += self.0;
+    }
+}
+
+impl Display for AddX {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "addx {}", self.0)
+    }
+}
+
+pub type Program = Vec<Box<dyn Instruction>>;
+
+pub fn parse_instruction(value: &str) -> Result<Box<dyn Instruction>, String> {
+    let mut parts = value.split_whitespace();
+
+    match parts.next() {
+        Some("noop") => Ok(Box::new(NoOp)),
+        Some("addx") => {
+            let op = parts
+                .next()
+                .and_then(|x| x.parse().ok())
+                .ok_or(format!("Invalid instruction input: {}", value))?;
+            Ok(Box::new(AddX(op)))
+        }
+        _ => Err(format!("Invalid instruction input: {}", value)),
+    }
+}
+
+/// The machine state during one cycle
+#[derive(Clone, Copy, Debug)]
+pub struct Cycle<'a> {
+    /// Counting from 1
+    pub number: usize,
+    pub registers: Registers,
+    /// Index of the instruction being executed
+    pub pc: usize,
+    pub instruction: &'a dyn Instruction,
+}
+
+/// Called during every cycle of a run
+pub trait Hook {
+    fn on_cycle(&mut self, cycle: &Cycle);
+}
+
+impl<F: FnMut(&Cycle)> Hook for F {
+    fn on_cycle(&mut self, cycle: &Cycle) {
+        self(cycle)
+    }
+}
+
+/// Cycle-accurate scheduler: each instruction occupies the CPU for as many
+/// cycles as it takes, then takes effect before the next one starts
+pub struct Cpu<'a> {
+    program: &'a [Box<dyn Instruction>],
+    registers: Registers,
+    /// Cycles completed so far
+    cycles: usize,
+    pc: usize,
+    /// Cycles left before the current instruction completes
+    remaining: usize,
+}
+
+impl<'a> Cpu<'a> {
+    /// Load a program, which fails if any instruction takes no cycles, since
+    /// it could never be running during one
+    pub fn new(program: &'a [Box<dyn Instruction>]) -> Result<Self, String> {
+        if let Some((pc, instruction)) = program.iter().enumerate().find(|(_, i)| i.cycles() == 0) {
+            return Err(format!("Instruction {pc} ({instruction}) takes no cycles"));
+        }
+
+        Ok(Self {
+            program,
+            registers: Registers::default(),
+            cycles: 0,
+            pc: 0,
+            remaining: program.first().map_or(0, |i| i.cycles()),
+        })
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    pub fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.pc >= self.program.len()
+    }
+
+    /// Run a single cycle, returning the state during it, or None once the
+    /// program has finished
+    pub fn step(&mut self) -> Option<Cycle<'a>> {
+        let instruction = self.program.get(self.pc)?.as_ref();
+        let cycle = Cycle {
+            number: self.cycles + 1,
+            registers: self.registers,
+            pc: self.pc,
+            instruction,
+        };
+
+        self.cycles += 1;
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            instruction.execute(&mut self.registers);
+            self.pc += 1;
+            self.remaining = self.program.get(self.pc).map_or(0, |i| i.cycles());
+        }
+
+        Some(cycle)
+    }
+
+    /// Run the rest of the program, calling the hook during every cycle
+    pub fn run(&mut self, hook: &mut impl Hook) {
+        while let Some(cycle) = self.step() {
+            hook.on_cycle(&cycle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct Instant;
+
+    impl Instruction for Instant {
+        fn cycles(&self) -> usize {
+            0
+        }
+
+        fn execute(&self, _registers: &mut Registers) {}
+    }
+
+    impl Display for Instant {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "instant")
+        }
+    }
+
+    #[test]
+    fn zero_cycles() {
+        let program: Program = vec![Box::new(NoOp), Box::new(Instant)];
+        assert_eq!(
+            Cpu::new(&program).err(),
+            Some("Instruction 1 (instant) takes no cycles".into())
+        );
+
+        let mut cpu = Cpu::new(&program[..1]).unwrap();
+        assert!(cpu.step().is_some());
+        assert!(cpu.is_halted());
+    }
+}
@@ -0,0 +1,144 @@
+use std::fmt::Write;
+
+use crate::cpu::{Cpu, Cycle};
+
+/// Where the debugger should pause
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Breakpoint {
+    /// During the given cycle
+    Cycle(usize),
+    /// During the first cycle X has the given value, after it had a different one
+    X(i32),
+}
+
+impl Breakpoint {
+    fn hit(&self, cycle: &Cycle, previous: Option<&Cycle>) -> bool {
+        match *self {
+            Self::Cycle(n) => cycle.number == n,
+            Self::X(x) => cycle.registers.x == x && previous.is_none_or(|p| p.registers.x != x),
+        }
+    }
+}
+
+/// Why the debugger stopped running
+#[derive(Clone, Copy, Debug)]
+pub enum Stop<'a> {
+    Breakpoint(Breakpoint, Cycle<'a>),
+    Halted,
+}
+
+/// Runs a CPU a cycle at a time, pausing at breakpoints and keeping a trace
+/// of every cycle
+pub struct Debugger<'a> {
+    cpu: Cpu<'a>,
+    breakpoints: Vec<Breakpoint>,
+    trace: Vec<Cycle<'a>>,
+}
+
+impl<'a> Debugger<'a> {
+    pub fn new(cpu: Cpu<'a>) -> Self {
+        Self {
+            cpu,
+            breakpoints: Vec::new(),
+            trace: Vec::new(),
+        }
+    }
+
+    pub fn cpu(&self) -> &Cpu<'a> {
+        &self.cpu
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    pub fn remove_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.retain(|&b| b != breakpoint);
+    }
+
+    /// Run a single cycle
+    pub fn step(&mut self) -> Option<Cycle<'a>> {
+        let cycle = self.cpu.step()?;
+        self.trace.push(cycle);
+        Some(cycle)
+    }
+
+    /// Run until a breakpoint is hit or the program finishes
+    pub fn resume(&mut self) -> Stop<'a> {
+        while let Some(cycle) = self.step() {
+            let previous = self.trace.len().checked_sub(2).map(|i| &self.trace[i]);
+            if let Some(&b) = self.breakpoints.iter().find(|b| b.hit(&cycle, previous)) {
+                return Stop::Breakpoint(b, cycle);
+            }
+        }
+
+        Stop::Halted
+    }
+
+    /// Every cycle run so far
+    pub fn trace(&self) -> &[Cycle<'a>] {
+        &self.trace
+    }
+
+    /// One line per cycle run so far, e.g. `   3 | pc   1 | x   1 | addx 3`
+    pub fn dump_trace(&self) -> String {
+        self.trace.iter().fold(String::new(), |mut out, c| {
+            writeln!(
+                out,
+                "{:4} | pc {:3} | x {:3} | {}",
+                c.number, c.pc, c.registers.x, c.instruction
+            )
+            .unwrap();
+            out
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpu::{parse_instruction, Program};
+
+    fn program() -> Program {
+        ["noop", "addx 3", "addx -5", "noop"]
+            .into_iter()
+            .map(|i| parse_instruction(i).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn breakpoints() {
+        let program = program();
+        let mut debugger = Debugger::new(Cpu::new(&program).unwrap());
+        debugger.add_breakpoint(Breakpoint::X(4));
+        debugger.add_breakpoint(Breakpoint::Cycle(5));
+
+        // addx 3 finishes at the end of cycle 3
+        let Stop::Breakpoint(Breakpoint::X(4), cycle) = debugger.resume() else {
+            panic!("Expected to stop when X became 4");
+        };
+        assert_eq!(cycle.number, 4);
+
+        let Stop::Breakpoint(Breakpoint::Cycle(5), cycle) = debugger.resume() else {
+            panic!("Expected to stop at cycle 5");
+        };
+        assert_eq!(cycle.registers.x, 4);
+        assert_eq!(debugger.cpu().registers().x, -1);
+
+        assert!(matches!(debugger.resume(), Stop::Halted));
+        assert_eq!(debugger.trace().len(), 6);
+    }
+
+    #[test]
+    fn trace() {
+        let program = program();
+        let mut debugger = Debugger::new(Cpu::new(&program).unwrap());
+        debugger.step();
+        debugger.step();
+
+        assert_eq!(
+            debugger.dump_trace(),
+            "   1 | pc   0 | x   1 | noop\n   2 | pc   1 | x   1 | addx 3\n"
+        );
+    }
+}
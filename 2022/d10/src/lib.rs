@@ -0,0 +1,92 @@
+use anyhow::{Error, Result};
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+
+mod cpu;
+mod debugger;
+
+pub use cpu::{parse_instruction, AddX, Cpu, Cycle, Hook, Instruction, NoOp, Program, Registers};
+pub use debugger::{Breakpoint, Debugger, Stop};
+
+/// Sums signal strengths (cycle number times X) sampled during every 40th
+/// cycle, starting from the 20th
+#[derive(Default)]
+pub struct SignalStrength {
+    pub sum: isize,
+}
+
+impl SignalStrength {
+    const SAMPLE_START: usize = 20;
+    const SAMPLE_FREQ: usize = 40;
+}
+
+impl Hook for SignalStrength {
+    fn on_cycle(&mut self, cycle: &Cycle) {
+        // signal strength is sampled *during* the cycle, before it completes
+        if cycle.number >= Self::SAMPLE_START
+            && (cycle.number - Self::SAMPLE_START).is_multiple_of(Self::SAMPLE_FREQ)
+        {
+            self.sum += (cycle.registers.x as isize) * (cycle.number as isize);
+        }
+    }
+}
+
+/// Draws one pixel per cycle into a framebuffer, lit when the 3 pixel wide
+/// sprite centred on X covers it
+pub struct Crt {
+    pub framebuffer: Grid<bool>,
+}
+
+impl Crt {
+    const PIXEL_WIDTH: usize = 40;
+    const PIXEL_HEIGHT: usize = 6;
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Self {
+            framebuffer: Grid::filled(Self::PIXEL_HEIGHT, Self::PIXEL_WIDTH, false),
+        }
+    }
+}
+
+impl Hook for Crt {
+    fn on_cycle(&mut self, cycle: &Cycle) {
+        let pixel = cycle.number - 1;
+        let (row, col) = (pixel / Self::PIXEL_WIDTH, pixel % Self::PIXEL_WIDTH);
+        if let Some(lit) = self.framebuffer.get_mut(row, col) {
+            *lit = (col as i32 - cycle.registers.x).abs() <= 1;
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Program;
+    type Part1 = isize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| parse_instruction(line).map_err(Error::msg))
+            .collect()
+    }
+
+    fn part1(program: &Self::Input) -> Result<isize> {
+        let mut signal = SignalStrength::default();
+        Cpu::new(program).map_err(Error::msg)?.run(&mut signal);
+
+        Ok(signal.sum)
+    }
+
+    fn part2(program: &Self::Input) -> Result<String> {
+        let mut crt = Crt::default();
+        Cpu::new(program).map_err(Error::msg)?.run(&mut crt);
+
+        aoc_ocr::decode(&crt.framebuffer)
+    }
+}
+
+aoc_solution::expected_answers!(Day10);
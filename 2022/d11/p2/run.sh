@@ -1 +0,0 @@
-cd monkey && cargo run -- ../../input.txt
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Worry;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl TryFrom<char> for Operator {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '+' => Ok(Operator::Add),
+            '-' => Ok(Operator::Subtract),
+            '*' => Ok(Operator::Multiply),
+            '/' => Ok(Operator::Divide),
+            _ => Err(format!("Invalid operator: {}", value)),
+        }
+    }
+}
+
+/// Arithmetic on the old worry level, e.g. `old * (old + 3) - 2`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expr {
+    Old,
+    Literal(u64),
+    Binary(Box<Expr>, Operator, Box<Expr>),
+}
+
+/// Why an expression couldn't be evaluated
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EvalError {
+    /// Too big for the worry level type, which a bigger type might fix
+    Overflow,
+    DivisionByZero,
+    Negative,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Overflow => write!(f, "Worry level overflowed"),
+            Self::DivisionByZero => write!(f, "Division by zero"),
+            Self::Negative => write!(f, "Worry level went negative"),
+        }
+    }
+}
+
+impl Expr {
+    /// Evaluate the expression. With a modulus, the result is reduced by it
+    /// and subtraction wraps around instead of going negative.
+    pub fn eval<W: Worry>(&self, old: &W, modulus: Option<&W>) -> Result<W, EvalError> {
+        match self {
+            Self::Old => Ok(old.clone()),
+            Self::Literal(x) => Ok(W::from(*x)),
+            Self::Binary(left, op, right) => {
+                let (left, right) = (left.eval(old, modulus)?, right.eval(old, modulus)?);
+                let result = match (op, modulus) {
+                    (Operator::Add, _) => left.checked_add(&right).ok_or(EvalError::Overflow)?,
+                    (Operator::Subtract, Some(m)) => left
+                        .checked_add(m)
+                        .and_then(|l| l.checked_sub(&(right % m.clone())))
+                        .ok_or(EvalError::Overflow)?,
+                    (Operator::Subtract, None) => {
+                        left.checked_sub(&right).ok_or(EvalError::Negative)?
+                    }
+                    (Operator::Multiply, _) => {
+                        left.checked_mul(&right).ok_or(EvalError::Overflow)?
+                    }
+                    (Operator::Divide, _) => {
+                        left.checked_div(&right).ok_or(EvalError::DivisionByZero)?
+                    }
+                };
+
+                Ok(match modulus {
+                    Some(m) => result % m.clone(),
+                    None => result,
+                })
+            }
+        }
+    }
+
+    /// Division doesn't survive reducing worry levels modulo anything
+    pub fn divides(&self) -> bool {
+        match self {
+            Self::Old | Self::Literal(_) => false,
+            Self::Binary(left, op, right) => {
+                *op == Operator::Divide || left.divides() || right.divides()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Old,
+    Number(u64),
+    Operator(Operator),
+    Open,
+    Close,
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' => {
+                chars.next();
+                tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            }
+            c if c.is_ascii_digit() => {
+                let mut n = 0u64;
+                while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+                    n = n
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(d as u64))
+                        .ok_or(format!("Number too big in: {}", s))?;
+                    chars.next();
+                }
+                tokens.push(Token::Number(n));
+            }
+            c if c.is_ascii_alphabetic() => {
+                let word = std::iter::from_fn(|| chars.next_if(|c| c.is_ascii_alphanumeric()))
+                    .collect::<String>();
+                match word.as_str() {
+                    "old" => tokens.push(Token::Old),
+                    _ => return Err(format!("Unknown variable {} in: {}", word, s)),
+                }
+            }
+            c => {
+                tokens.push(Token::Operator(c.try_into()?));
+                chars.next();
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser, with * and / binding tighter than + and -
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// Left-associative chain of operands joined by any of ops
+    fn chain(
+        &mut self,
+        ops: &[Operator],
+        operand: fn(&mut Self) -> Result<Expr, String>,
+    ) -> Result<Expr, String> {
+        let mut expr = operand(self)?;
+        while let Some(&Token::Operator(op)) = self.peek() {
+            if !ops.contains(&op) {
+                break;
+            }
+            self.next();
+            expr = Expr::Binary(Box::new(expr), op, Box::new(operand(self)?));
+        }
+
+        Ok(expr)
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        self.chain(&[Operator::Add, Operator::Subtract], Self::term)
+    }
+
+    fn term(&mut self) -> Result<Expr, String> {
+        self.chain(&[Operator::Multiply, Operator::Divide], Self::factor)
+    }
+
+    fn factor(&mut self) -> Result<Expr, String> {
+        match self.next() {
+            Some(Token::Old) => Ok(Expr::Old),
+            Some(Token::Number(n)) => Ok(Expr::Literal(n)),
+            Some(Token::Open) => {
+                let expr = self.expr()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(expr),
+                    _ => Err("Missing )".into()),
+                }
+            }
+            Some(token) => Err(format!("Unexpected {:?}", token)),
+            None => Err("Unexpected end of expression".into()),
+        }
+    }
+}
+
+impl FromStr for Expr {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            tokens: tokenize(s)?,
+            pos: 0,
+        };
+
+        let expr = parser
+            .expr()
+            .map_err(|e| format!("Invalid expression {}: {}", s, e))?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some(token) => Err(format!("Invalid expression {}: unexpected {:?}", s, token)),
+        }
+    }
+}
@@ -0,0 +1,474 @@
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use anyhow::{anyhow, Result};
+use aoc_cycle::{detect_cycle, Cycle};
+use aoc_solution::Solution;
+use itertools::Itertools;
+use num::{BigUint, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Integer};
+
+mod expr;
+
+pub use expr::{EvalError, Expr, Operator};
+
+/// Integer type for worry levels
+pub trait Worry:
+    Clone + Hash + Debug + From<u64> + Integer + CheckedAdd + CheckedSub + CheckedMul + CheckedDiv
+{
+}
+
+impl<T> Worry for T where
+    T: Clone
+        + Hash
+        + Debug
+        + From<u64>
+        + Integer
+        + CheckedAdd
+        + CheckedSub
+        + CheckedMul
+        + CheckedDiv
+{
+}
+
+#[derive(Clone)]
+pub struct Operation(pub Expr);
+
+impl TryFrom<String> for Operation {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value
+            .trim_start()
+            .strip_prefix("Operation: new = ")
+            .ok_or(format!("Invalid operation: {}", value))?
+            .parse()
+            .map(Operation)
+    }
+}
+
+#[derive(Clone)]
+pub enum Condition {
+    DivisibleBy(u64),
+}
+
+impl Condition {
+    fn applies_to<W: Worry>(&self, x: &W) -> bool {
+        match *self {
+            Self::DivisibleBy(y) => x.is_multiple_of(&W::from(y)),
+        }
+    }
+}
+
+impl TryFrom<String> for Condition {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value
+            .trim_start_matches("  Test: divisible by ")
+            .parse()
+            .ok()
+            .filter(|&x| x != 0)
+            .map(Condition::DivisibleBy)
+            .ok_or(format!("Invalid condition: {}", value))
+    }
+}
+
+#[derive(Clone)]
+pub struct Test {
+    condition: Condition,
+    true_throw: usize,
+    false_throw: usize,
+}
+
+#[derive(Clone)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    operation: Operation,
+    test: Test,
+}
+
+impl TryFrom<Vec<String>> for Monkey {
+    type Error = String;
+
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        let mut iter = value.into_iter();
+
+        let items = iter
+            .by_ref()
+            .nth(1)
+            .and_then(|line| {
+                line.split(": ").nth(1).and_then(|items| {
+                    items
+                        .split(", ")
+                        .map(|x| x.parse())
+                        .collect::<Result<VecDeque<u64>, _>>()
+                        .ok()
+                })
+            })
+            .ok_or("Cannot parse items")?;
+
+        let operation = iter
+            .by_ref()
+            .next()
+            .ok_or("Missing operation")?
+            .try_into()?;
+
+        let condition = iter
+            .by_ref()
+            .next()
+            .and_then(|line| line.try_into().ok())
+            .ok_or("Cannot parse test condition")?;
+
+        let true_throw = iter
+            .by_ref()
+            .next()
+            .and_then(|line| {
+                line.trim_start_matches("    If true: throw to monkey ")
+                    .parse()
+                    .ok()
+            })
+            .ok_or("Cannot parse true case")?;
+
+        let false_throw = iter
+            .by_ref()
+            .next()
+            .and_then(|line| {
+                line.trim_start_matches("    If false: throw to monkey ")
+                    .parse()
+                    .ok()
+            })
+            .ok_or("Cannot parse false case")?;
+
+        Ok(Monkey {
+            items,
+            operation,
+            test: Test {
+                condition,
+                true_throw,
+                false_throw,
+            },
+        })
+    }
+}
+
+impl Monkey {
+    /// Inspect an item, returning its new worry level and which monkey it's
+    /// thrown to
+    fn inspect<W: Worry>(
+        &self,
+        worry: &W,
+        relief: bool,
+        modulus: &W,
+    ) -> Result<(W, usize), EvalError> {
+        let worry = if relief {
+            self.operation.0.eval(worry, None)? / W::from(3)
+        } else {
+            self.operation.0.eval(worry, Some(modulus))?
+        };
+
+        let throw_to = if self.test.condition.applies_to(&worry) {
+            self.test.true_throw
+        } else {
+            self.test.false_throw
+        };
+
+        Ok((worry, throw_to))
+    }
+}
+
+/// Least common multiple, or None if it overflows
+fn checked_lcm<W: Worry>(a: &W, b: &W) -> Option<W> {
+    (a.clone() / a.gcd(b)).checked_mul(b)
+}
+
+#[derive(Clone)]
+pub struct Monkeys {
+    pub monkeys: Vec<Monkey>,
+}
+
+impl Monkeys {
+    fn new(monkeys: Vec<Monkey>) -> Result<Monkeys> {
+        let n = monkeys.len();
+        if let Some(t) = monkeys
+            .iter()
+            .flat_map(|m| [m.test.true_throw, m.test.false_throw])
+            .find(|&t| t >= n)
+        {
+            return Err(anyhow!("Monkey {t} doesn't exist"));
+        }
+
+        Ok(Monkeys { monkeys })
+    }
+
+    /// Without relief, worry levels are kept in check modulo the least common
+    /// multiple of the test divisors, which leaves every test's result unchanged
+    fn modulus<W: Worry>(&self) -> Option<W> {
+        self.monkeys
+            .iter()
+            .map(|m| match m.test.condition {
+                Condition::DivisibleBy(x) => W::from(x),
+            })
+            .try_fold(W::one(), |lcm, x| checked_lcm(&lcm, &x))
+    }
+
+    /// Follow one item through a round, returning the monkeys that inspect it
+    /// and where it ends up
+    fn round<W: Worry>(
+        &self,
+        (mut monkey, mut worry): (usize, W),
+        relief: bool,
+        modulus: &W,
+    ) -> Result<(Vec<usize>, (usize, W)), EvalError> {
+        let mut inspectors = Vec::new();
+
+        // monkeys take turns in order, so an item thrown to a later monkey is
+        // inspected again in the same round
+        loop {
+            inspectors.push(monkey);
+            let (new_worry, throw_to) = self.monkeys[monkey].inspect(&worry, relief, modulus)?;
+            worry = new_worry;
+
+            let later = throw_to > monkey;
+            monkey = throw_to;
+            if !later {
+                return Ok((inspectors, (monkey, worry)));
+            }
+        }
+    }
+
+    /// How many times each monkey inspects one item over some rounds of keep
+    /// away. Items never affect each other, so each one can be followed on its
+    /// own until it's back where it was at the start of an earlier round,
+    /// after which it repeats that cycle.
+    fn item_inspections<W: Worry>(
+        &self,
+        item: (usize, W),
+        rounds: usize,
+        relief: bool,
+        modulus: &W,
+    ) -> Result<Vec<usize>, EvalError> {
+        // once an item can't be inspected it stays that way, which also ends
+        // the search
+        let (cycle, _) = detect_cycle(Ok(item.clone()), rounds, |item| {
+            let item = item.clone()?;
+            self.round(item, relief, modulus).map(|(_, item)| item)
+        });
+        let end = cycle.map_or(rounds, |c| c.prefix + c.period);
+
+        // total inspections by each monkey at the start of each round, up to
+        // the end of the first cycle
+        let mut history = vec![vec![0; self.monkeys.len()]];
+        let mut item = item;
+        for _ in 0..rounds.min(end) {
+            let (inspectors, next) = self.round(item, relief, modulus)?;
+            let mut counts = history[history.len() - 1].clone();
+            for monkey in inspectors {
+                counts[monkey] += 1;
+            }
+            history.push(counts);
+            item = next;
+        }
+
+        // extrapolate the rest of the rounds
+        match cycle {
+            Some(Cycle { prefix, period }) if rounds > end => {
+                let (cycles, rest) = ((rounds - prefix) / period, (rounds - prefix) % period);
+                let counts = (0..self.monkeys.len())
+                    .map(|i| {
+                        let per_cycle = history[end][i] - history[prefix][i];
+                        history[prefix + rest][i] + cycles * per_cycle
+                    })
+                    .collect();
+                Ok(counts)
+            }
+            _ => Ok(history.pop().expect("History starts with the first round")),
+        }
+    }
+
+    /// How many times each monkey inspects items over some rounds of keep
+    /// away, with worry levels of type W, or None if they overflow. Fails if
+    /// an operation divides by zero or takes a worry level below zero.
+    pub fn inspections<W: Worry>(&self, rounds: usize, relief: bool) -> Result<Option<Vec<usize>>> {
+        if !relief && self.monkeys.iter().any(|m| m.operation.0.divides()) {
+            return Err(anyhow!(
+                "Worry levels can't be kept in check when operations divide"
+            ));
+        }
+
+        let Some(modulus) = self.modulus::<W>() else {
+            return Ok(None);
+        };
+
+        let mut counts = vec![0; self.monkeys.len()];
+        for (i, monkey) in self.monkeys.iter().enumerate() {
+            for item in &monkey.items {
+                let item_counts =
+                    match self.item_inspections((i, W::from(*item)), rounds, relief, &modulus) {
+                        Ok(item_counts) => item_counts,
+                        Err(EvalError::Overflow) => return Ok(None),
+                        Err(e) => return Err(anyhow!(e)),
+                    };
+
+                for (total, n) in counts.iter_mut().zip(item_counts) {
+                    *total += n;
+                }
+            }
+        }
+
+        Ok(Some(counts))
+    }
+}
+
+/// Product of the two highest inspection counts after some rounds. Worry
+/// levels are u64 unless they overflow, in which case the game is replayed
+/// with big integers.
+fn play(monkeys: &Monkeys, rounds: usize, relief: bool) -> Result<usize> {
+    let counts = match monkeys.inspections::<u64>(rounds, relief)? {
+        Some(counts) => counts,
+        None => monkeys
+            .inspections::<BigUint>(rounds, relief)?
+            .ok_or_else(|| anyhow!(EvalError::Overflow))?,
+    };
+
+    Ok(counts.into_iter().sorted().rev().take(2).product())
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Monkeys;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let monkeys = input
+            .lines()
+            .map(String::from)
+            .group_by(|line| !line.is_empty())
+            .into_iter()
+            .filter(|(key, _)| *key)
+            .map(|(_, group)| group.collect::<Vec<String>>().try_into())
+            .collect::<Result<Vec<Monkey>, String>>()
+            .map_err(|e| anyhow!(e))?;
+
+        Monkeys::new(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<usize> {
+        play(monkeys, 20, true)
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<usize> {
+        play(monkeys, 10_000, false)
+    }
+}
+
+aoc_solution::expected_answers!(Day11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn expressions() {
+        let expr: Expr = "old * (old + 3) - 2 * 4".parse().unwrap();
+        assert_eq!(expr.eval(&5u64, None), Ok(32));
+        assert_eq!(expr.eval(&5u64, Some(&7)), Ok(4));
+        assert_eq!(expr.eval(&1u64, None), Err(EvalError::Negative));
+        assert_eq!(expr.eval(&1u64, Some(&7)), Ok(3));
+        assert!(!expr.divides());
+
+        let expr: Expr = "old / 2 / 2".parse().unwrap();
+        assert_eq!(expr.eval(&9u64, None), Ok(2));
+        assert!(expr.divides());
+
+        let expr: Expr = "old / (old - 3)".parse().unwrap();
+        assert_eq!(expr.eval(&3u64, None), Err(EvalError::DivisionByZero));
+        let expr: Expr = "old * old".parse().unwrap();
+        assert_eq!(expr.eval(&u64::MAX, None), Err(EvalError::Overflow));
+
+        assert!("old +".parse::<Expr>().is_err());
+        assert!("(old * 2".parse::<Expr>().is_err());
+        assert!("new * 2".parse::<Expr>().is_err());
+        assert!("old 2".parse::<Expr>().is_err());
+    }
+
+    /// Play round by round, passing every item along
+    fn simulate(monkeys: &Monkeys, rounds: usize) -> Vec<usize> {
+        let modulus: u64 = monkeys.modulus().unwrap();
+        let mut items = monkeys
+            .monkeys
+            .iter()
+            .map(|m| m.items.clone())
+            .collect::<Vec<_>>();
+        let mut counts = vec![0; items.len()];
+        for _ in 0..rounds {
+            for (i, monkey) in monkeys.monkeys.iter().enumerate() {
+                while let Some(item) = items[i].pop_front() {
+                    counts[i] += 1;
+                    let (item, throw_to) = monkey.inspect(&item, false, &modulus).unwrap();
+                    items[throw_to].push_back(item);
+                }
+            }
+        }
+
+        counts
+    }
+
+    #[test]
+    fn extrapolates_cycles() {
+        let monkeys = Day11::parse(EXAMPLE).unwrap();
+        for rounds in [1, 20, 1000, 2345] {
+            assert_eq!(
+                monkeys.inspections::<u64>(rounds, false).unwrap(),
+                Some(simulate(&monkeys, rounds))
+            );
+        }
+
+        let counts = monkeys
+            .inspections::<u64>(1_000_000_000_000, false)
+            .unwrap()
+            .unwrap();
+        assert!(counts.iter().all(|&n| n > 1_000_000_000));
+    }
+
+    #[test]
+    fn big_worry() {
+        // the divisors' LCM doesn't fit in a u64
+        let input = EXAMPLE
+            .replace("divisible by 23", "divisible by 4294967291")
+            .replace("divisible by 19", "divisible by 4294967279")
+            .replace("divisible by 13", "divisible by 4294967231");
+        let monkeys = Day11::parse(&input).unwrap();
+        assert_eq!(monkeys.inspections::<u64>(100, false).unwrap(), None);
+        assert_eq!(
+            monkeys
+                .inspections::<BigUint>(100, false)
+                .unwrap()
+                .map(|counts| counts.len()),
+            Some(4)
+        );
+        assert!(play(&monkeys, 100, false).is_ok());
+
+        let input = EXAMPLE.replace("new = old * 19", "new = old / 2");
+        let monkeys = Day11::parse(&input).unwrap();
+        assert!(play(&monkeys, 20, true).is_ok());
+        assert!(play(&monkeys, 20, false).is_err());
+    }
+
+    #[test]
+    fn negative_worry() {
+        let input = EXAMPLE.replace("new = old * 19", "new = old - 100");
+        let monkeys = Day11::parse(&input).unwrap();
+        assert_eq!(
+            play(&monkeys, 20, true).unwrap_err().to_string(),
+            "Worry level went negative"
+        );
+
+        // wrapping around the modulus instead
+        assert!(play(&monkeys, 20, false).is_ok());
+    }
+}
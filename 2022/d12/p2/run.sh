@@ -1 +0,0 @@
-cd hill && cargo run -- ../../input.txt
@@ -0,0 +1,97 @@
+use anyhow::{anyhow, Error, Result};
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+use pathfinding::prelude::astar;
+
+pub type Point = aoc_geometry::Point<usize>;
+
+pub struct HeightMap {
+    pub heights: Grid<u8>,
+    pub start: Point,
+    pub dest: Point,
+}
+
+impl HeightMap {
+    pub fn at(&self, p: &Point) -> u8 {
+        self.heights[(p.y, p.x)]
+    }
+
+    pub fn shortest_path_len(&self, from: &Point, to: &Point) -> Option<usize> {
+        astar(
+            from,
+            |p| self.neighbors(p).into_iter().map(|loc| (loc, 1)),
+            |p| p.manhattan(to),
+            |p| p == to,
+        )
+        .map(|(path, _)| path.len() - 1)
+    }
+
+    pub fn neighbors(&self, p: &Point) -> Vec<Point> {
+        self.heights
+            .neighbors4(p.y, p.x)
+            .map(|(y, x)| Point::new(x, y))
+            .filter(|loc| self.at(p) >= self.at(loc) || self.at(p).abs_diff(self.at(loc)) <= 1)
+            .collect()
+    }
+}
+
+impl TryFrom<&str> for HeightMap {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let squares: Grid<char> = value.parse().map_err(|e| format!("{:#}", e))?;
+
+        let find = |square| {
+            squares
+                .position(|&c| c == square)
+                .map(|(y, x)| Point::new(x, y))
+                .ok_or(format!("Missing {} square", square))
+        };
+        let start = find('S')?;
+        let dest = find('E')?;
+
+        if let Some(h) = squares.iter().find(|h| !matches!(h, 'S' | 'E' | 'a'..='z')) {
+            return Err(format!("Invalid height: {}", h));
+        }
+
+        let heights = squares.map(|&h| match h {
+            'S' => 0,
+            'E' => b'z' - b'a',
+            _ => h as u8 - b'a',
+        });
+
+        Ok(HeightMap {
+            heights,
+            start,
+            dest,
+        })
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = HeightMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.try_into().map_err(Error::msg)
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        map.shortest_path_len(&map.start, &map.dest)
+            .ok_or(anyhow!("No path"))
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        map.heights
+            .enumerate()
+            .filter(|(_, &h)| h == 0)
+            .filter_map(|((y, x), _)| map.shortest_path_len(&Point::new(x, y), &map.dest))
+            .min()
+            .ok_or(anyhow!("No path"))
+    }
+}
+
+aoc_solution::expected_answers!(Day12);
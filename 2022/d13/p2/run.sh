@@ -1 +0,0 @@
-cd distress && cargo run -- ../../input.txt
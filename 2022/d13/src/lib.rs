@@ -0,0 +1,68 @@
+use std::collections::{BinaryHeap, HashSet};
+
+use anyhow::{anyhow, Error, Result};
+use aoc_solution::Solution;
+
+mod packet;
+
+pub use packet::{Data, Explanation, Packets, Reason};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Data>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Packets::new(input.chars())
+            .map(|packet| packet.map_err(Error::msg))
+            .collect()
+    }
+
+    fn part1(packets: &Self::Input) -> Result<usize> {
+        let pairs = packets.chunks_exact(2);
+        if !pairs.remainder().is_empty() {
+            return Err(anyhow!("Odd number of packets: {}", packets.len()));
+        }
+
+        Ok(pairs
+            .enumerate()
+            .filter_map(|(i, pair)| (pair[0] < pair[1]).then_some(i + 1))
+            .sum())
+    }
+
+    fn part2(packets: &Self::Input) -> Result<usize> {
+        let dividers: HashSet<Data> = ["[[2]]", "[[6]]"]
+            .into_iter()
+            .map(|x| x.parse().map_err(Error::msg))
+            .collect::<Result<_>>()?;
+
+        Ok(packets
+            .iter()
+            .chain(dividers.iter())
+            .cloned()
+            .collect::<BinaryHeap<Data>>()
+            .into_sorted_vec()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, packet)| dividers.contains(&packet).then_some(i + 1))
+            .product())
+    }
+}
+
+aoc_solution::expected_answers!(Day13);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn odd_packets() {
+        let packets = Day13::parse("[1]\n[2]\n\n[3]\n").unwrap();
+        assert_eq!(
+            Day13::part1(&packets).unwrap_err().to_string(),
+            "Odd number of packets: 3"
+        );
+    }
+}
@@ -0,0 +1,320 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::iter::Peekable;
+use std::slice;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Data {
+    Integer(u32),
+    List(Vec<Data>),
+}
+
+impl Data {
+    /// Integers compare as if they were a list holding just themselves
+    fn as_list(&self) -> &[Data] {
+        match self {
+            Self::Integer(_) => slice::from_ref(self),
+            Self::List(items) => items,
+        }
+    }
+
+    /// Compare two packets, reporting what decided the order
+    pub fn explain(&self, other: &Self) -> Explanation {
+        let mut path = Vec::new();
+        let reason = explain(self, other, &mut path);
+        Explanation { path, reason }
+    }
+}
+
+/// Print in the same format as the puzzle input, so the output parses back
+/// to the same packet
+impl fmt::Display for Data {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Integer(x) => write!(f, "{}", x),
+            Self::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl FromStr for Data {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut packets = Packets::new(s.chars());
+        let packet = packets.next().ok_or("No packet")??;
+        match packets.next() {
+            None => Ok(packet),
+            Some(_) => Err("Unexpected trailing input after packet".into()),
+        }
+    }
+}
+
+impl Ord for Data {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(x), Self::Integer(y)) => x.cmp(y),
+            (x, y) => x.as_list().iter().cmp(y.as_list().iter()),
+        }
+    }
+}
+
+impl PartialOrd for Data {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// What decided the order of two packets
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Reason {
+    /// Two integers differed
+    Integers(u32, u32),
+    /// One list ran out first, after this many equal items
+    Length(usize, usize),
+    /// Nothing did: the packets are equal
+    Equal,
+}
+
+/// The first point two packets differ, found while comparing them
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Explanation {
+    /// Index into each enclosing list of the items being compared, where an
+    /// integer compared against a list counts as a list of itself
+    pub path: Vec<usize>,
+    pub reason: Reason,
+}
+
+impl Explanation {
+    pub fn ordering(&self) -> Ordering {
+        match self.reason {
+            Reason::Integers(x, y) => x.cmp(&y),
+            Reason::Length(x, y) => x.cmp(&y),
+            Reason::Equal => Ordering::Equal,
+        }
+    }
+
+    /// Whether the pair is in the right order, i.e. the left packet is smaller
+    pub fn in_order(&self) -> bool {
+        self.ordering() == Ordering::Less
+    }
+}
+
+/// e.g. `at [1][0]: left ran out of items first (1 < 3)`
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "at ")?;
+        if self.path.is_empty() {
+            write!(f, "top level")?;
+        }
+        for i in &self.path {
+            write!(f, "[{}]", i)?;
+        }
+
+        let sign = match self.ordering() {
+            Ordering::Less => '<',
+            Ordering::Equal => '=',
+            Ordering::Greater => '>',
+        };
+        match self.reason {
+            Reason::Integers(x, y) => write!(f, ": {} {} {}", x, sign, y),
+            Reason::Length(x, y) if x < y => {
+                write!(f, ": left ran out of items first ({} {} {})", x, sign, y)
+            }
+            Reason::Length(x, y) => {
+                write!(f, ": right ran out of items first ({} {} {})", x, sign, y)
+            }
+            Reason::Equal => write!(f, ": packets are equal"),
+        }
+    }
+}
+
+/// Same rules as `Ord`, keeping track of where the comparison is. The path
+/// is left pointing at whatever decided the order.
+fn explain(left: &Data, right: &Data, path: &mut Vec<usize>) -> Reason {
+    if let (Data::Integer(x), Data::Integer(y)) = (left, right) {
+        return match x.cmp(y) {
+            Ordering::Equal => Reason::Equal,
+            _ => Reason::Integers(*x, *y),
+        };
+    }
+
+    let (left, right) = (left.as_list(), right.as_list());
+    for (i, (a, b)) in left.iter().zip(right).enumerate() {
+        path.push(i);
+        match explain(a, b, path) {
+            Reason::Equal => path.pop(),
+            reason => return reason,
+        };
+    }
+
+    match left.len().cmp(&right.len()) {
+        Ordering::Equal => Reason::Equal,
+        _ => Reason::Length(left.len(), right.len()),
+    }
+}
+
+/// Streaming parser for whitespace-separated packets, reading one character
+/// at a time and keeping unfinished lists on a stack rather than recursing
+pub struct Packets<I: Iterator<Item = char>> {
+    chars: Peekable<I>,
+    /// Characters consumed so far, for error messages
+    pos: usize,
+}
+
+impl<I: Iterator<Item = char>> Packets<I> {
+    pub fn new(chars: I) -> Self {
+        Self {
+            chars: chars.peekable(),
+            pos: 0,
+        }
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn integer(&mut self) -> Result<u32, String> {
+        let mut x = 0u32;
+        while let Some(d) = self.chars.peek().and_then(|c| c.to_digit(10)) {
+            x = x
+                .checked_mul(10)
+                .and_then(|x| x.checked_add(d))
+                .ok_or(format!("Integer too big at {}", self.pos))?;
+            self.next_char();
+        }
+
+        Ok(x)
+    }
+
+    fn packet(&mut self) -> Result<Data, String> {
+        let mut stack: Vec<Vec<Data>> = Vec::new();
+        // whether the next item in the innermost list needs a comma first
+        let mut separated = false;
+
+        loop {
+            let c = *self.chars.peek().ok_or("Unexpected end of packet")?;
+            let item = match c {
+                '[' if !separated => {
+                    self.next_char();
+                    stack.push(Vec::new());
+                    continue;
+                }
+                ']' if separated || stack.last().is_some_and(Vec::is_empty) => {
+                    self.next_char();
+                    let list = stack.pop().ok_or(format!("Unmatched ] at {}", self.pos))?;
+                    Data::List(list)
+                }
+                ',' if separated => {
+                    self.next_char();
+                    separated = false;
+                    continue;
+                }
+                c if c.is_ascii_digit() && !separated => Data::Integer(self.integer()?),
+                c => return Err(format!("Unexpected {:?} at {}", c, self.pos + 1)),
+            };
+
+            match stack.last_mut() {
+                Some(list) => {
+                    list.push(item);
+                    separated = true;
+                }
+                None => return Ok(item),
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Packets<I> {
+    type Item = Result<Data, String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.chars.next_if(|c| c.is_whitespace()).is_some() {
+            self.pos += 1;
+        }
+        self.chars.peek()?;
+
+        Some(self.packet())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn pairs() -> Vec<(Data, Data)> {
+        let packets = Packets::new(EXAMPLE.chars())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        packets
+            .chunks_exact(2)
+            .map(|pair| (pair[0].clone(), pair[1].clone()))
+            .collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        for line in EXAMPLE.lines().filter(|line| !line.is_empty()) {
+            assert_eq!(line.parse::<Data>().unwrap().to_string(), line);
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        for (packet, error) in [
+            ("", "No packet"),
+            ("[1,2", "Unexpected end of packet"),
+            ("[1,,2]", "Unexpected ',' at 4"),
+            ("[1,]", "Unexpected ']' at 4"),
+            ("[1 2]", "Unexpected ' ' at 3"),
+            ("[1]]", "Unexpected trailing input after packet"),
+            ("[1] [2]", "Unexpected trailing input after packet"),
+            ("[4294967296]", "Integer too big at 10"),
+        ] {
+            assert_eq!(packet.parse::<Data>().unwrap_err(), error);
+        }
+    }
+
+    #[test]
+    fn explain() {
+        let explanations = pairs()
+            .iter()
+            .map(|(left, right)| {
+                let explanation = left.explain(right);
+                assert_eq!(explanation.ordering(), left.cmp(right));
+                explanation.to_string()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            explanations,
+            [
+                "at [2]: 3 < 5",
+                "at [1][0]: 2 < 4",
+                "at [0][0]: 9 > 8",
+                "at top level: left ran out of items first (3 < 4)",
+                "at top level: right ran out of items first (4 > 3)",
+                "at top level: left ran out of items first (0 < 1)",
+                "at [0]: right ran out of items first (1 > 0)",
+                "at [1][1][1][1][2]: 7 > 0",
+            ]
+        );
+
+        let packet = "[[1],2]".parse::<Data>().unwrap();
+        assert_eq!(packet.explain(&packet).reason, Reason::Equal);
+    }
+}
@@ -1 +0,0 @@
-cd beacon && cargo run -- ../../input.txt 2000000
@@ -1 +0,0 @@
-cd beacon && cargo run -- ../../input.txt 0 4000000
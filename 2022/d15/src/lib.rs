@@ -0,0 +1,319 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_geometry::Point;
+use aoc_interval::{Interval, IntervalSet};
+use aoc_solution::{Parameters, Solution};
+
+/// Parse a point like `x=2, y=18`
+fn parse_point(s: &str) -> Result<Point, String> {
+    let mut parts = s.split(", ");
+
+    let x = parts
+        .next()
+        .and_then(|s| s.trim_start_matches("x=").parse().ok())
+        .ok_or(format!("Invalid point input: {}", s))?;
+
+    let y = parts
+        .next()
+        .and_then(|s| s.trim_start_matches("y=").parse().ok())
+        .ok_or(format!("Invalid point input: {}", s))?;
+
+    Ok(Point::new(x, y))
+}
+
+pub struct Sensor {
+    pub loc: Point,
+    pub closest_beacon: Point,
+    pub beacon_distance: isize,
+}
+
+impl Sensor {
+    fn new(loc: Point, closest_beacon: Point) -> Self {
+        let beacon_distance = loc.manhattan(&closest_beacon);
+
+        Self {
+            loc,
+            closest_beacon,
+            beacon_distance,
+        }
+    }
+
+    /// Whether the point is no further away than the closest beacon, so can't
+    /// hold an undetected beacon
+    pub fn covers(&self, p: &Point) -> bool {
+        self.loc.manhattan(p) <= self.beacon_distance
+    }
+
+    /// x values in row y covered by the sensor's diamond
+    pub fn row(&self, y: isize) -> Option<Interval<isize>> {
+        let offset = self.beacon_distance - (y - self.loc.y).abs();
+        (offset >= 0).then(|| Interval::inclusive(self.loc.x - offset, self.loc.x + offset))
+    }
+
+    /// Lines running along the outside of the diamond, just out of range: the
+    /// values of x + y for the two rising edges, and of x - y for the falling ones
+    fn boundary(&self) -> ([isize; 2], [isize; 2]) {
+        let (sum, diff, r) = (
+            self.loc.x + self.loc.y,
+            self.loc.x - self.loc.y,
+            self.beacon_distance + 1,
+        );
+        ([sum - r, sum + r], [diff - r, diff + r])
+    }
+}
+
+impl FromStr for Sensor {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split(": ");
+
+        let loc = parts
+            .next()
+            .and_then(|s| parse_point(s.trim_start_matches("Sensor at ")).ok())
+            .ok_or(format!("Invalid sensor input: {}", s))?;
+
+        let closest_beacon = parts
+            .next()
+            .and_then(|s| parse_point(s.trim_start_matches("closest beacon is at ")).ok())
+            .ok_or(format!("Invalid sensor input: {}", s))?;
+
+        Ok(Sensor::new(loc, closest_beacon))
+    }
+}
+
+pub struct Tunnels {
+    sensors: Vec<Sensor>,
+    /// Every sensor and beacon, sorted and without duplicates
+    occupied: Vec<Point>,
+}
+
+impl Tunnels {
+    pub fn new(sensors: Vec<Sensor>) -> Self {
+        let mut occupied = sensors
+            .iter()
+            .flat_map(|s| [s.loc, s.closest_beacon])
+            .collect::<Vec<_>>();
+        occupied.sort_by_key(|p| (p.y, p.x));
+        occupied.dedup();
+
+        Self { sensors, occupied }
+    }
+
+    pub fn sensors(&self) -> &[Sensor] {
+        &self.sensors
+    }
+
+    /// The first sensor covering the point, if any
+    pub fn covered_by(&self, p: &Point) -> Option<&Sensor> {
+        self.sensors.iter().find(|s| s.covers(p))
+    }
+
+    pub fn is_covered(&self, p: &Point) -> bool {
+        self.covered_by(p).is_some()
+    }
+
+    /// x values in row y that fall inside some sensor's exclusive zone
+    pub fn row(&self, y: isize) -> IntervalSet<isize> {
+        self.sensors.iter().filter_map(|s| s.row(y)).collect()
+    }
+
+    pub fn count_empty(&self, y: isize) -> usize {
+        // count covered points, less any sensors and beacons among them
+        let row = self.row(y);
+        let start = self.occupied.partition_point(|p| p.y < y);
+        let end = self.occupied.partition_point(|p| p.y <= y);
+        let occupied = self.occupied[start..end]
+            .iter()
+            .filter(|p| row.contains(p.x))
+            .count();
+
+        row.len() as usize - occupied
+    }
+
+    /// Every point within bounds, on both axes, that no sensor covers,
+    /// assuming there are only a few and each is boxed in by sensors. Such a
+    /// point lies just outside the diamonds around it, so is where a rising
+    /// and a falling boundary line cross, where a boundary line meets an edge
+    /// of the bounds, or in a corner of the bounds.
+    pub fn find_gaps(&self, bounds: &Interval<isize>) -> Vec<Point> {
+        let Some(last) = bounds.last() else {
+            return Vec::new();
+        };
+        let edges = [bounds.start, last];
+
+        let (rising, falling): (Vec<_>, Vec<_>) = self.sensors.iter().map(|s| s.boundary()).unzip();
+        let (rising, falling) = (rising.concat(), falling.concat());
+
+        let crossings = rising.iter().flat_map(|sum| {
+            falling
+                .iter()
+                .filter(move |diff| (sum - *diff) % 2 == 0)
+                .map(move |diff| Point::new((sum + diff) / 2, (sum - diff) / 2))
+        });
+        // x + y = sum and x - y = diff, at x or y on an edge
+        let on_edges = edges.into_iter().flat_map(|e| {
+            let rising = rising
+                .iter()
+                .flat_map(move |sum| [Point::new(e, sum - e), Point::new(sum - e, e)]);
+            let falling = falling
+                .iter()
+                .flat_map(move |diff| [Point::new(e, e - diff), Point::new(e + diff, e)]);
+            rising.chain(falling)
+        });
+        let corners = edges
+            .into_iter()
+            .flat_map(|x| edges.map(|y| Point::new(x, y)));
+
+        let mut gaps = crossings
+            .chain(on_edges)
+            .chain(corners)
+            .filter(|p| bounds.contains(p.x) && bounds.contains(p.y) && !self.is_covered(p))
+            .collect::<Vec<_>>();
+        gaps.sort_by_key(|p| (p.y, p.x));
+        gaps.dedup();
+
+        gaps
+    }
+}
+
+/// The row to count in and the size of the area to search, which the
+/// example shrinks down with the `row` and `search` parameters
+const ROW: isize = 2_000_000;
+const SEARCH: isize = 4_000_000;
+
+/// The tunnels and where to look in them
+pub struct Survey {
+    pub tunnels: Tunnels,
+    pub row: isize,
+    pub search: isize,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Survey;
+    type Part1 = usize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Parameters::default())
+    }
+
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input> {
+        parameters.allow_only(&["row", "search"])?;
+        let sensors = input
+            .lines()
+            .map(|line| line.parse().map_err(Error::msg))
+            .collect::<Result<_>>()?;
+
+        Ok(Survey {
+            tunnels: Tunnels::new(sensors),
+            row: parameters.get("row")?.unwrap_or(ROW),
+            search: parameters.get("search")?.unwrap_or(SEARCH),
+        })
+    }
+
+    fn part1(survey: &Self::Input) -> Result<usize> {
+        Ok(survey.tunnels.count_empty(survey.row))
+    }
+
+    fn part2(survey: &Self::Input) -> Result<isize> {
+        let empty = survey
+            .tunnels
+            .find_gaps(&Interval::inclusive(0, survey.search));
+
+        match empty.as_slice() {
+            [beacon] => Ok(beacon.x * 4_000_000 + beacon.y),
+            _ => Err(anyhow!(
+                "Expected exactly one empty spot, found {}",
+                empty.len()
+            )),
+        }
+    }
+}
+
+aoc_solution::expected_answers!(Day15);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    /// Every point in 0..=size on both axes that no sensor covers, scanning
+    /// row by row
+    fn scan(tunnels: &Tunnels, size: isize) -> Vec<Point> {
+        (0..=size)
+            .flat_map(|y| {
+                let row = tunnels.row(y);
+                (0..=size)
+                    .filter(move |&x| !row.contains(x))
+                    .map(move |x| Point::new(x, y))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rows() {
+        let tunnels = Day15::parse(EXAMPLE).unwrap().tunnels;
+
+        let intervals = |y| tunnels.row(y).iter().copied().collect::<Vec<_>>();
+        assert_eq!(intervals(10), [Interval::inclusive(-2, 24)]);
+        assert_eq!(
+            intervals(11),
+            [Interval::inclusive(-3, 13), Interval::inclusive(15, 25)]
+        );
+        assert_eq!(tunnels.count_empty(10), 26);
+    }
+
+    #[test]
+    fn gaps() {
+        let tunnels = Day15::parse(EXAMPLE).unwrap().tunnels;
+
+        assert_eq!(
+            tunnels.find_gaps(&Interval::inclusive(0, 20)),
+            [Point::new(14, 11)]
+        );
+        assert!(tunnels.covered_by(&Point::new(14, 11)).is_none());
+
+        let sensor = tunnels.covered_by(&Point::new(13, 11)).unwrap();
+        assert_eq!(sensor.loc, Point::new(12, 14));
+
+        assert_eq!(scan(&tunnels, 20), [Point::new(14, 11)]);
+    }
+
+    #[test]
+    fn gap_on_edge() {
+        // every boundary line through (0, 10) is x - y = -10, so it isn't
+        // where any two of them cross
+        let tunnels = Day15::parse(
+            "\
+Sensor at x=1, y=0: closest beacon is at x=11, y=0
+Sensor at x=-10, y=20: closest beacon is at x=-10, y=39
+Sensor at x=10, y=0: closest beacon is at x=29, y=0
+Sensor at x=30, y=30: closest beacon is at x=30, y=78",
+        )
+        .unwrap()
+        .tunnels;
+
+        assert_eq!(scan(&tunnels, 20), [Point::new(0, 10)]);
+        assert_eq!(
+            tunnels.find_gaps(&Interval::inclusive(0, 20)),
+            [Point::new(0, 10)]
+        );
+    }
+
+    #[test]
+    fn parameters() {
+        let survey = Day15::parse(EXAMPLE).unwrap();
+        assert_eq!((survey.row, survey.search), (ROW, SEARCH));
+
+        let survey = Day15::parse_with(EXAMPLE, &"row=10 search=20".parse().unwrap()).unwrap();
+        assert_eq!(Day15::part1(&survey).unwrap(), 26);
+        assert_eq!(Day15::part2(&survey).unwrap(), 56000011);
+
+        assert!(Day15::parse_with(EXAMPLE, &"rows=10".parse().unwrap()).is_err());
+    }
+}
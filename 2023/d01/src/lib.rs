@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+use anyhow::Result;
+use aoc_solution::Solution;
+use regex::{Match, Regex};
+
+const DIGITS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+const DIGITS_REV: [&str; 9] = [
+    "eno", "owt", "eerht", "ruof", "evif", "xis", "neves", "thgie", "enin",
+];
+
+// trie regex - see create_regex.py
+// matches any numeric or word digit efficiently
+const RE_DIGITS: &str = r"(?:f(?:ive|our)|s(?:even|ix)|t(?:hree|wo)|eight|nine|one|\d)";
+const RE_DIGITS_REV: &str = r"(?:e(?:n(?:in|o)|erht|vif)|neves|thgie|ruof|owt|xis|\d)";
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<u32> {
+        Ok(lines
+            .iter()
+            .filter_map(|line| {
+                let mut digits = line
+                    .chars()
+                    .filter_map(|c| c.is_ascii_digit().then_some(c as u32 - '0' as u32));
+
+                digits
+                    .next()
+                    .map(|first| (first * 10) + digits.next_back().unwrap_or(first))
+            })
+            .sum())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<usize> {
+        let re_digits = Regex::new(RE_DIGITS)?;
+        let re_digits_rev = Regex::new(RE_DIGITS_REV)?;
+
+        // map digit word to digit value, e.g. "two" -> 2
+        let mut digit_map: HashMap<&'static str, usize> =
+            HashMap::from_iter(DIGITS.iter().enumerate().map(|(i, d)| (*d, i + 1)));
+
+        // also map reversed word to digit value, e.g. "owt" -> 2
+        digit_map.extend(DIGITS_REV.iter().enumerate().map(|(i, d)| (*d, i + 1)));
+
+        // convert regex match to usize
+        let to_digit = |m: Match| match m.len() {
+            1 => m.as_str().parse::<usize>().unwrap(),
+            _ => digit_map[m.as_str()],
+        };
+
+        Ok(lines
+            .iter()
+            .filter_map(|line| {
+                re_digits.find(line).map(to_digit).map(|first| {
+                    let rev = &line.chars().rev().collect::<String>();
+                    let last = re_digits_rev.find(rev).map(to_digit).unwrap_or(first);
+                    first * 10 + last
+                })
+            })
+            .sum())
+    }
+}
+
+aoc_solution::expected_answers!(Day01);
@@ -1,16 +1,15 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::cmp;
 use std::str::FromStr;
 
 use anyhow::{anyhow, Context, Error, Result};
+use aoc_solution::Solution;
 
 const MAX_RED: usize = 12;
 const MAX_GREEN: usize = 13;
 const MAX_BLUE: usize = 14;
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: usize,
     draws: Vec<Draw>,
 }
@@ -24,6 +23,14 @@ impl Game {
         }
         true
     }
+
+    pub fn min_draw(&self) -> Draw {
+        self.draws.iter().fold(Draw::default(), |acc, draw| Draw {
+            red: cmp::max(acc.red, draw.red),
+            green: cmp::max(acc.green, draw.green),
+            blue: cmp::max(acc.blue, draw.blue),
+        })
+    }
 }
 
 impl FromStr for Game {
@@ -53,12 +60,18 @@ impl FromStr for Game {
 }
 
 #[derive(Debug, Default)]
-struct Draw {
+pub struct Draw {
     red: usize,
     green: usize,
     blue: usize,
 }
 
+impl Draw {
+    pub fn power(&self) -> usize {
+        self.red * self.green * self.blue
+    }
+}
+
 impl FromStr for Draw {
     type Err = Error;
 
@@ -84,27 +97,34 @@ impl FromStr for Draw {
     }
 }
 
-fn main() -> Result<(), Error> {
-    let args: Vec<String> = env::args().collect();
-    let input = File::open(&args[1])?;
-    let reader = io::BufReader::new(input);
-
-    let games = reader
-        .lines()
-        .filter_map(|line| line.ok())
-        .enumerate()
-        .map(|(i, line)| {
-            line.parse()
-                .with_context(|| format!("Couldn't parse Game on line {}", i + 1))
-        })
-        .collect::<Result<Vec<Game>, _>>()?;
-
-    let sum: usize = games
-        .iter()
-        .filter_map(|g| g.is_possible(MAX_RED, MAX_GREEN, MAX_BLUE).then_some(g.id))
-        .sum();
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.parse()
+                    .with_context(|| format!("Couldn't parse Game on line {}", i + 1))
+            })
+            .collect()
+    }
 
-    println!("Sum: {}", sum);
+    fn part1(games: &Self::Input) -> Result<usize> {
+        Ok(games
+            .iter()
+            .filter_map(|g| g.is_possible(MAX_RED, MAX_GREEN, MAX_BLUE).then_some(g.id))
+            .sum())
+    }
 
-    Ok(())
+    fn part2(games: &Self::Input) -> Result<usize> {
+        Ok(games.iter().map(|g| g.min_draw().power()).sum())
+    }
 }
+
+aoc_solution::expected_answers!(Day02);
@@ -1,15 +1,13 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
 use std::ops::Range;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
+use aoc_solution::Solution;
 use bit_set::BitSet;
 
 /// Number with location in row
 #[derive(Debug)]
-struct Number {
+pub struct Number {
     pub value: u32,
     pub start: usize,
     pub len: usize,
@@ -20,33 +18,45 @@ impl Number {
     pub fn range(&self) -> Range<usize> {
         self.start..(self.start + self.len)
     }
+
+    /// Test if gear has adjacent column index with any of this numbers digits
+    pub fn is_adjacent(&self, gear: usize) -> bool {
+        self.start <= gear + 1 && gear <= self.start + self.len
+    }
 }
 
 /// Schematic row
 #[derive(Debug)]
-struct Row {
+pub struct Row {
     /// numbers occurring in the row
     numbers: Vec<Number>,
     /// bits are set for indices containing numeric digits
     number_set: BitSet,
     /// bits are set for indices containing or adjacent to a symbol
     symbol_set: BitSet,
+    /// gears occurring in the row
+    gear_indices: Vec<usize>,
 }
 
 impl Row {
-    /// Create a new row from the given numbers and symbol indices
-    pub fn new<S: IntoIterator<Item = usize>>(numbers: Vec<Number>, symbol_indices: S) -> Self {
+    /// Create a new row from the given numbers, symbol indices and gear indices
+    pub fn new<S: IntoIterator<Item = usize>>(
+        numbers: Vec<Number>,
+        symbol_indices: S,
+        gear_indices: Vec<usize>,
+    ) -> Self {
         // bits are set for indices containing numeric digits
         let number_set = BitSet::from_iter(numbers.iter().flat_map(Number::range));
 
         // bits are set for indices containing or adjacent to a symbol
         let symbol_set =
-            BitSet::from_iter(symbol_indices.into_iter().flat_map(|i| (i - 1)..=(i + 1)));
+            BitSet::from_iter(symbol_indices.into_iter().flat_map(|i| i.saturating_sub(1)..=(i + 1)));
 
         Self {
             numbers,
             number_set,
             symbol_set,
+            gear_indices,
         }
     }
 }
@@ -60,7 +70,7 @@ impl FromStr for Row {
             .chars()
             .enumerate()
             .filter_map(|(i, c)| (c != '.').then_some((i, c)))
-            .partition(|(_, c)| c.is_digit(10));
+            .partition(|(_, c)| c.is_ascii_digit());
 
         // accumulate adjacent digits into numbers
         let numbers = digits.iter().fold(Vec::new(), |mut acc, (i, c)| {
@@ -87,14 +97,18 @@ impl FromStr for Row {
 
         // discard symbols, we just need indices
         let symbol_indices = symbols.iter().map(|(i, _)| *i);
+        let gear_indices = symbols
+            .iter()
+            .filter_map(|(i, c)| (*c == '*').then_some(*i))
+            .collect();
 
-        Ok(Self::new(numbers, symbol_indices))
+        Ok(Self::new(numbers, symbol_indices, gear_indices))
     }
 }
 
 /// Engine schematic
 #[derive(Debug)]
-struct Schematic {
+pub struct Schematic {
     rows: Vec<Row>,
 }
 
@@ -127,28 +141,70 @@ impl Schematic {
                 .filter_map(move |n| n.range().any(|x| mask.contains(x)).then_some(n.value))
         })
     }
-}
 
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-    let input = File::open(&args[1])?;
-    let reader = io::BufReader::new(input);
+    pub fn gear_ratios<'a>(&'a self) -> impl Iterator<Item = u32> + 'a {
+        (0..self.rows.len()).flat_map(|i| {
+            let curr = &self.rows[i];
 
-    let schematic = Schematic::new(
-        reader
-            .lines()
-            .filter_map(|line| line.ok())
-            .enumerate()
-            .map(|(i, line)| {
-                line.parse()
-                    .with_context(|| format!("Couldn't parse Row on line {}", i + 1))
+            // get iterator of all numbers in current and adjacent rows
+            let curr_nums = curr.numbers.iter();
+            let prev_nums = (i > 0)
+                .then(|| &self.rows[i - 1].numbers)
+                .into_iter()
+                .flatten();
+            let next_nums = (i < self.rows.len() - 1)
+                .then(|| &self.rows[i + 1].numbers)
+                .into_iter()
+                .flatten();
+
+            // collect into vec so we can re-use for each gear
+            let numbers = prev_nums
+                .chain(curr_nums)
+                .chain(next_nums)
+                .collect::<Vec<_>>();
+
+            // get gear ratios for gears with exactly 2 adjacent numbers
+            curr.gear_indices.iter().filter_map(move |g| {
+                // get adjacent numbers
+                let adj = numbers
+                    .iter()
+                    .filter_map(|n| n.is_adjacent(*g).then_some(n.value))
+                    .collect::<Vec<_>>();
+
+                // if there are exactly 2 adjacent gears, return ratio
+                (adj.len() == 2).then(|| adj.iter().product())
             })
-            .collect::<Result<Vec<Row>>>()?,
-    );
+        })
+    }
+}
 
-    let sum: u32 = schematic.part_numbers().sum();
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Schematic;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Schematic::new(
+            input
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    line.parse()
+                        .with_context(|| format!("Couldn't parse Row on line {}", i + 1))
+                })
+                .collect::<Result<Vec<Row>>>()?,
+        ))
+    }
 
-    println!("Sum of Part Numbers: {}", sum);
+    fn part1(schematic: &Self::Input) -> Result<u32> {
+        Ok(schematic.part_numbers().sum())
+    }
 
-    Ok(())
+    fn part2(schematic: &Self::Input) -> Result<u32> {
+        Ok(schematic.gear_ratios().sum())
+    }
 }
+
+aoc_solution::expected_answers!(Day03);
@@ -1,13 +1,11 @@
 use std::collections::BTreeSet;
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
 use std::str::FromStr;
 
 use anyhow::{anyhow, Context, Error, Result};
+use aoc_solution::Solution;
 
 #[derive(Debug)]
-struct Card {
+pub struct Card {
     _id: usize,
     winning_numbers: BTreeSet<u8>,
     card_numbers: BTreeSet<u8>,
@@ -19,6 +17,13 @@ impl Card {
             .intersection(&self.winning_numbers)
             .count()
     }
+
+    pub fn value(&self) -> usize {
+        match self.matching_numbers() {
+            0 => 0,
+            c => 2usize.pow(c as u32 - 1),
+        }
+    }
 }
 
 impl FromStr for Card {
@@ -46,11 +51,10 @@ impl FromStr for Card {
             .next()
             .ok_or(anyhow!("Missing winning numbers"))?
             .split(" ")
-            .filter_map(|c| {
-                (!c.is_empty()).then(|| {
-                    c.parse()
-                        .with_context(|| format!("Invalid winning number: {}", c))
-                })
+            .filter(|&c| !c.is_empty())
+            .map(|c| {
+                c.parse()
+                    .with_context(|| format!("Invalid winning number: {}", c))
             })
             .collect::<Result<_>>()?;
 
@@ -58,11 +62,10 @@ impl FromStr for Card {
             .next()
             .ok_or(anyhow!("Missing card numbers"))?
             .split(" ")
-            .filter_map(|c| {
-                (!c.is_empty()).then(|| {
-                    c.parse()
-                        .with_context(|| format!("Invalid card number: {}", c))
-                })
+            .filter(|&c| !c.is_empty())
+            .map(|c| {
+                c.parse()
+                    .with_context(|| format!("Invalid card number: {}", c))
             })
             .collect::<Result<_>>()?;
 
@@ -74,7 +77,7 @@ impl FromStr for Card {
     }
 }
 
-struct Table {
+pub struct Table {
     cards: Vec<Card>,
 }
 
@@ -102,24 +105,33 @@ impl Table {
     }
 }
 
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-    let input = File::open(&args[1])?;
-    let reader = io::BufReader::new(input);
-
-    let cards = reader
-        .lines()
-        .filter_map(|line| line.ok())
-        .enumerate()
-        .map(|(i, line)| {
-            line.parse()
-                .with_context(|| format!("Couldn't parse Card on line {}", i + 1))
-        })
-        .collect::<Result<_>>()?;
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Table;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let cards = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.parse()
+                    .with_context(|| format!("Couldn't parse Card on line {}", i + 1))
+            })
+            .collect::<Result<_>>()?;
 
-    let table = Table { cards };
+        Ok(Table { cards })
+    }
 
-    println!("Total Cards: {}", table.total());
+    fn part1(table: &Self::Input) -> Result<usize> {
+        Ok(table.cards.iter().map(Card::value).sum())
+    }
 
-    Ok(())
+    fn part2(table: &Self::Input) -> Result<usize> {
+        Ok(table.total())
+    }
 }
+
+aoc_solution::expected_answers!(Day04);
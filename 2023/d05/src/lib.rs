@@ -1,61 +1,27 @@
-use std::cmp::Ordering;
 use std::collections::BTreeSet;
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
 use std::str::FromStr;
 
 use anyhow::{anyhow, Context, Error, Result};
+use aoc_interval::{Interval, IntervalSet};
+use aoc_solution::Solution;
 use itertools::Itertools;
 
-/// std::ops::Range<usize> doesn't implement Ord
-#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
-struct Range {
-    /// start of range, inclusive
-    start: usize,
-    /// end of range, exclusive
-    end: usize,
-}
-
-impl Range {
-    pub fn new(start: usize, len: usize) -> Self {
-        Self {
-            start,
-            end: start + len,
-        }
-    }
-
-    pub fn contains(&self, idx: usize) -> bool {
-        self.start <= idx && idx < self.end
-    }
-}
-
 /// Maps a source range to destination range of equal length
 #[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
-struct RangeMapping {
+pub struct RangeMapping {
     /// source range
-    src: Range,
-    /// destination range
-    dst: Range,
+    src: Interval<usize>,
+    /// start of destination range
+    dst_start: usize,
 }
 
 impl RangeMapping {
     pub fn new(src_start: usize, dst_start: usize, len: usize) -> Self {
         Self {
-            src: Range::new(src_start, len),
-            dst: Range::new(dst_start, len),
+            src: Interval::with_len(src_start, len),
+            dst_start,
         }
     }
-
-    pub fn convert(&self, idx: usize) -> Option<usize> {
-        self.src
-            .contains(idx)
-            .then(|| match self.src.start.cmp(&self.dst.start) {
-                Ordering::Less => idx + (self.dst.start - self.src.start),
-                Ordering::Greater => idx - (self.src.start - self.dst.start),
-                Ordering::Equal => idx,
-            })
-    }
 }
 
 impl FromStr for RangeMapping {
@@ -86,7 +52,7 @@ impl FromStr for RangeMapping {
 }
 
 #[derive(Debug, Default)]
-struct Map {
+pub struct Map {
     ranges: BTreeSet<RangeMapping>,
 }
 
@@ -103,17 +69,25 @@ impl Map {
         Ok(Self { ranges })
     }
 
-    pub fn get(&self, idx: usize) -> usize {
-        self.ranges
-            .iter()
-            .filter_map(|r| r.convert(idx))
-            .next()
-            .unwrap_or(idx)
+    /// Convert every value in the set, splitting ranges that are
+    /// only partially covered by a range mapping
+    pub fn convert(&self, values: &IntervalSet<usize>) -> IntervalSet<usize> {
+        let mut unmapped = values.clone();
+        let mut mapped = IntervalSet::new();
+
+        for r in self.ranges.iter() {
+            let (inside, outside) = unmapped.split(&r.src);
+            mapped.extend(inside.iter().map(|i| i.translate(r.src.start, r.dst_start)));
+            unmapped = outside;
+        }
+
+        // values not covered by any range mapping are unchanged
+        mapped.union(&unmapped)
     }
 }
 
 #[derive(Debug)]
-struct Almanac {
+pub struct Almanac {
     seeds: Vec<usize>,
     maps: Vec<Map>,
 }
@@ -124,7 +98,7 @@ impl Almanac {
         let groups = lines.into_iter().group_by(|x| !x.is_empty());
         let mut groups = groups
             .into_iter()
-            .filter_map(|(not_empty, group)| not_empty.then(|| group));
+            .filter_map(|(not_empty, group)| not_empty.then_some(group));
 
         // first group is seeds line
         let seeds = groups
@@ -146,25 +120,48 @@ impl Almanac {
         Ok(Self { seeds, maps })
     }
 
-    pub fn locations(&self) -> impl Iterator<Item = usize> {
+    /// Seed values interpreted as pairs of range start and length
+    pub fn seed_ranges(&self) -> IntervalSet<usize> {
+        self.seeds
+            .chunks_exact(2)
+            .map(|c| Interval::with_len(c[0], c[1]))
+            .collect()
+    }
+
+    pub fn locations(&self, seeds: &IntervalSet<usize>) -> IntervalSet<usize> {
         self.maps
             .iter()
-            .fold(self.seeds.clone(), |mut acc, map| {
-                acc.iter_mut().for_each(|i| *i = map.get(*i));
-                acc
-            })
-            .into_iter()
+            .fold(seeds.clone(), |acc, map| map.convert(&acc))
     }
 }
 
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-    let input = File::open(&args[1])?;
-    let reader = io::BufReader::new(input);
+pub struct Day05;
 
-    let almanac = Almanac::try_from_lines(reader.lines().filter_map(|line| line.ok()))?;
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("Minimum Location: {}", almanac.locations().min().unwrap());
+    fn parse(input: &str) -> Result<Self::Input> {
+        Almanac::try_from_lines(input.lines().map(String::from))
+    }
 
-    Ok(())
+    fn part1(almanac: &Self::Input) -> Result<usize> {
+        let seeds = almanac
+            .seeds
+            .iter()
+            .map(|&s| Interval::with_len(s, 1))
+            .collect();
+
+        almanac.locations(&seeds).min().ok_or(anyhow!("No seeds"))
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<usize> {
+        almanac
+            .locations(&almanac.seed_ranges())
+            .min()
+            .ok_or(anyhow!("No seeds"))
+    }
 }
+
+aoc_solution::expected_answers!(Day05);
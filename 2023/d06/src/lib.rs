@@ -0,0 +1,287 @@
+use std::fmt::Debug;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use aoc_solution::Solution;
+use num::{BigUint, CheckedMul, Integer};
+
+/// Integer type for race times and distances
+pub trait Int: Clone + Debug + Integer + CheckedMul + FromStr + From<u8> {}
+
+impl<T: Clone + Debug + Integer + CheckedMul + FromStr + From<u8>> Int for T {}
+
+/// Largest x with x * x <= n, by Newton's method
+pub fn isqrt<T: Int>(n: &T) -> T {
+    let two = T::from(2);
+    if *n < two {
+        return n.clone();
+    }
+
+    // start above the root; each step then gets closer without overshooting,
+    // until rounding down makes it stop moving
+    let mut x = n.clone() / two.clone() + T::one();
+    loop {
+        let y = (x.clone() + n.clone() / x.clone()) / two.clone();
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+#[derive(Debug)]
+pub struct Race<T = usize> {
+    /// Race time
+    time: T,
+    /// Record distance
+    distance: T,
+}
+
+impl<T: Int> Race<T> {
+    /// Whether holding the button for some time beats the record, or None if
+    /// the distance overflows
+    fn wins(&self, hold_time: &T) -> Option<bool> {
+        let distance = hold_time.checked_mul(&(self.time.clone() - hold_time.clone()))?;
+        Some(distance > self.distance)
+    }
+
+    /// Number of hold times that beat the record, or None if the numbers
+    /// overflow T
+    pub fn ways_to_win(&self) -> Option<T> {
+        // hold times h win when h * (time - h) > distance, which is between
+        // the roots of h^2 - time * h + distance, placed symmetrically around
+        // time / 2
+        let (two, four) = (T::from(2), T::from(4));
+        let square = self.time.checked_mul(&self.time)?;
+        let record = self.distance.checked_mul(&four)?;
+        if record > square {
+            return Some(T::zero());
+        }
+
+        // estimate the lower root, then correct for rounding
+        let half = self.time.clone() / two.clone();
+        let mut lower = (self.time.clone() - isqrt(&(square - record))) / two.clone();
+        while lower <= half && !self.wins(&lower)? {
+            lower = lower + T::one();
+        }
+        while lower > T::zero() && self.wins(&(lower.clone() - T::one()))? {
+            lower = lower - T::one();
+        }
+
+        if lower > half {
+            return Some(T::zero());
+        }
+
+        Some(self.time.clone() - lower * two + T::one())
+    }
+}
+
+/// Race sheet with the numbers for each column kept as written
+#[derive(Debug)]
+pub struct Sheet {
+    times: Vec<String>,
+    distances: Vec<String>,
+}
+
+impl Sheet {
+    /// Each column is a separate race
+    pub fn races<T: Int>(&self) -> Result<Vec<Race<T>>> {
+        self.times
+            .iter()
+            .zip(self.distances.iter())
+            .map(|(time, distance)| {
+                Ok(Race {
+                    time: time.parse().map_err(|_| anyhow!("Invalid time: {time}"))?,
+                    distance: distance
+                        .parse()
+                        .map_err(|_| anyhow!("Invalid distance: {distance}"))?,
+                })
+            })
+            .collect()
+    }
+
+    /// Ignore the spaces between columns; it's actually just one race
+    pub fn race<T: Int>(&self) -> Result<Race<T>> {
+        let (time, distance) = (self.times.concat(), self.distances.concat());
+        Ok(Race {
+            time: time.parse().map_err(|_| anyhow!("Invalid time: {time}"))?,
+            distance: distance
+                .parse()
+                .map_err(|_| anyhow!("Invalid distance: {distance}"))?,
+        })
+    }
+}
+
+/// An answer worked out in T, converted to a big integer, or None if it
+/// overflows T
+type Attempt = fn(&Sheet) -> Result<Option<BigUint>>;
+
+/// Product of the number of ways to win each race
+fn ways_to_win_races<T: Int + Into<BigUint>>(sheet: &Sheet) -> Result<Option<BigUint>> {
+    Ok(sheet
+        .races::<T>()?
+        .iter()
+        .try_fold(T::one(), |product, race| {
+            product.checked_mul(&race.ways_to_win()?)
+        })
+        .map(Into::into))
+}
+
+/// Number of ways to win the single race
+fn ways_to_win_race<T: Int + Into<BigUint>>(sheet: &Sheet) -> Result<Option<BigUint>> {
+    Ok(sheet.race::<T>()?.ways_to_win().map(Into::into))
+}
+
+/// Try each attempt with bigger types as the numbers overflow, where the last
+/// uses big integers. Numbers too big to even parse as a smaller type count
+/// as overflowing it.
+fn widening(sheet: &Sheet, attempts: &[Attempt]) -> Result<BigUint> {
+    let (last, smaller) = attempts.split_last().ok_or(anyhow!("Nothing to try"))?;
+    match smaller
+        .iter()
+        .find_map(|attempt| attempt(sheet).ok().flatten())
+    {
+        Some(answer) => Ok(answer),
+        None => last(sheet)?.ok_or(anyhow!("Numbers are too big")),
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Sheet;
+    type Part1 = BigUint;
+    type Part2 = BigUint;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = input.lines();
+
+        let times = lines
+            .next()
+            .ok_or(anyhow!("Missing times"))?
+            .strip_prefix("Time:")
+            .ok_or(anyhow!("Missing Time: prefix"))?
+            .split_whitespace()
+            .map(String::from)
+            .collect();
+
+        let distances = lines
+            .next()
+            .ok_or(anyhow!("Missing distances"))?
+            .strip_prefix("Distance:")
+            .ok_or(anyhow!("Missing Distance: prefix"))?
+            .split_whitespace()
+            .map(String::from)
+            .collect();
+
+        Ok(Sheet { times, distances })
+    }
+
+    fn part1(sheet: &Self::Input) -> Result<BigUint> {
+        widening(
+            sheet,
+            &[
+                ways_to_win_races::<u64>,
+                ways_to_win_races::<u128>,
+                ways_to_win_races::<BigUint>,
+            ],
+        )
+    }
+
+    fn part2(sheet: &Self::Input) -> Result<BigUint> {
+        // concatenated numbers can get big
+        widening(
+            sheet,
+            &[
+                ways_to_win_race::<u64>,
+                ways_to_win_race::<u128>,
+                ways_to_win_race::<BigUint>,
+            ],
+        )
+    }
+}
+
+aoc_solution::expected_answers!(Day06);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Try every hold time
+    fn brute_force(race: &Race<u64>) -> u64 {
+        (1..race.time)
+            .filter(|hold_time| hold_time * (race.time - hold_time) > race.distance)
+            .count() as u64
+    }
+
+    #[test]
+    fn square_roots() {
+        for n in 0..10_000u64 {
+            let root = isqrt(&n);
+            assert!(root * root <= n && (root + 1) * (root + 1) > n);
+        }
+        assert_eq!(isqrt(&u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt(&u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn overflow() {
+        let race = Race::<u64> {
+            time: 1 << 40,
+            distance: 1 << 60,
+        };
+        assert_eq!(race.ways_to_win(), None);
+
+        let wide = Race::<u128> {
+            time: 1 << 40,
+            distance: 1 << 60,
+        };
+        let big = Race::<BigUint> {
+            time: BigUint::from(1u64 << 40),
+            distance: BigUint::from(1u64 << 60),
+        };
+        assert_eq!(
+            BigUint::from(wide.ways_to_win().unwrap()),
+            big.ways_to_win().unwrap()
+        );
+
+        // too big to square even in a u128
+        let sheet = Day06::parse("Time: 1 00000 00000 00000 00000 00000\nDistance: 9\n").unwrap();
+        assert_eq!(
+            Day06::part2(&sheet).unwrap().to_string(),
+            "9999999999999999999999999"
+        );
+
+        // the product of the races overflows a u128
+        let sheet = Day06::parse(&format!(
+            "Time: {}\nDistance: {}\n",
+            "100000000000 ".repeat(4),
+            "0 ".repeat(4)
+        ))
+        .unwrap();
+        assert_eq!(
+            Day06::part1(&sheet).unwrap(),
+            BigUint::from(99_999_999_999u64).pow(4)
+        );
+        assert!(Day06::parse("Time: 7 x\nDistance: 9 1\n")
+            .and_then(|sheet| Day06::part1(&sheet))
+            .is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(time in 0..2000u64, distance in 0..1_000_000u64) {
+            let race = Race { time, distance };
+            prop_assert_eq!(race.ways_to_win(), Some(brute_force(&race)));
+        }
+
+        #[test]
+        fn close_to_the_record(time in 0..2000u64, offset in 0..3u64) {
+            // records right around the best possible distance
+            let distance = (time / 2 * (time - time / 2)).saturating_sub(offset);
+            let race = Race { time, distance };
+            prop_assert_eq!(race.ways_to_win(), Some(brute_force(&race)));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Error, Result};
+use aoc_solution::Solution;
+
+mod ranking;
+
+pub use ranking::{HandType, Jokers, NoWildcards, Ranking, Rules, WildcardRule};
+
+/// Cards as dealt, along with the bid on them; how they rank depends on the
+/// rules
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Hand {
+    cards: Vec<char>,
+    bid: usize,
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.cards.iter().collect::<String>())
+    }
+}
+
+impl FromStr for Hand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split(" ");
+
+        let cards = parts
+            .next()
+            .ok_or(anyhow!("Missing cards"))?
+            .chars()
+            .collect();
+
+        let bid = parts
+            .next()
+            .ok_or(anyhow!("Missing bid"))?
+            .parse()
+            .context("Invalid bid")?;
+
+        Ok(Hand { cards, bid })
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input.lines().map(|line| line.parse()).collect()
+    }
+
+    fn part1(hands: &Self::Input) -> Result<usize> {
+        Rules::standard().total_winnings(hands)
+    }
+
+    fn part2(hands: &Self::Input) -> Result<usize> {
+        Rules::jokers().total_winnings(hands)
+    }
+}
+
+aoc_solution::expected_answers!(Day07);
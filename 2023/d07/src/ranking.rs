@@ -0,0 +1,300 @@
+use std::fmt;
+
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+use crate::Hand;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// Type of a hand with groups of matching cards of these sizes, largest
+    /// first. Hands with more than five cards are ranked by their best five.
+    pub fn from_groups(groups: &[usize]) -> Self {
+        match (groups.first().copied().unwrap_or(0), groups.get(1).copied()) {
+            (5.., _) => Self::FiveOfAKind,
+            (4, _) => Self::FourOfAKind,
+            (3, Some(2..)) => Self::FullHouse,
+            (3, _) => Self::ThreeOfAKind,
+            (2, Some(2..)) => Self::TwoPair,
+            (2, _) => Self::OnePair,
+            _ => Self::HighCard,
+        }
+    }
+}
+
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::HighCard => "high card",
+            Self::OnePair => "one pair",
+            Self::TwoPair => "two pair",
+            Self::ThreeOfAKind => "three of a kind",
+            Self::FullHouse => "full house",
+            Self::FourOfAKind => "four of a kind",
+            Self::FiveOfAKind => "five of a kind",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Which cards are wild, and what they add to the rest of a hand
+pub trait WildcardRule {
+    /// What to call a wildcard in explanations
+    fn name(&self) -> &str;
+
+    fn is_wild(&self, card: char) -> bool;
+
+    /// Sizes of the groups of matching cards, largest first, once some
+    /// wildcards have been added to a hand with the given groups
+    fn assign(&self, groups: Vec<usize>, wildcards: usize) -> Vec<usize>;
+}
+
+/// Every card is just itself
+pub struct NoWildcards;
+
+impl WildcardRule for NoWildcards {
+    fn name(&self) -> &str {
+        "wildcard"
+    }
+
+    fn is_wild(&self, _card: char) -> bool {
+        false
+    }
+
+    fn assign(&self, groups: Vec<usize>, _wildcards: usize) -> Vec<usize> {
+        groups
+    }
+}
+
+/// Jokers pretend to be whatever card makes the best hand, which is always
+/// more of whatever there's already the most of
+pub struct Jokers(pub char);
+
+impl WildcardRule for Jokers {
+    fn name(&self) -> &str {
+        "joker"
+    }
+
+    fn is_wild(&self, card: char) -> bool {
+        card == self.0
+    }
+
+    fn assign(&self, mut groups: Vec<usize>, wildcards: usize) -> Vec<usize> {
+        match groups.first_mut() {
+            Some(largest) => *largest += wildcards,
+            None if wildcards > 0 => groups.push(wildcards),
+            None => {}
+        }
+        groups
+    }
+}
+
+/// How a hand ranks under some rules, and why
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Ranking {
+    pub r#type: HandType,
+    /// Strength of each card in order, to break ties between hands of the
+    /// same type
+    pub strengths: Vec<usize>,
+    /// Type of the hand without its wildcards
+    pub natural: HandType,
+    pub wildcards: usize,
+    /// What wildcards are called
+    pub wildcard_name: String,
+}
+
+/// e.g. `two pair + 1 joker → full house`
+impl fmt::Display for Ranking {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.wildcards {
+            0 => write!(f, "{}", self.r#type),
+            1 => write!(
+                f,
+                "{} + 1 {} → {}",
+                self.natural, self.wildcard_name, self.r#type
+            ),
+            n => write!(
+                f,
+                "{} + {} {}s → {}",
+                self.natural, n, self.wildcard_name, self.r#type
+            ),
+        }
+    }
+}
+
+/// A variant of Camel Cards
+pub struct Rules {
+    /// Cards from weakest to strongest
+    order: Vec<char>,
+    hand_size: usize,
+    wildcards: Box<dyn WildcardRule>,
+}
+
+impl Rules {
+    /// Rules with cards ranked from weakest to strongest in the given order,
+    /// and no wildcards
+    pub fn new(order: &str, hand_size: usize) -> Self {
+        Self {
+            order: order.chars().collect(),
+            hand_size,
+            wildcards: Box::new(NoWildcards),
+        }
+    }
+
+    pub fn with_wildcards(self, wildcards: impl WildcardRule + 'static) -> Self {
+        Self {
+            wildcards: Box::new(wildcards),
+            ..self
+        }
+    }
+
+    /// The rules as first explained
+    pub fn standard() -> Self {
+        Self::new("23456789TJQKA", 5)
+    }
+
+    /// J cards are jokers, which are wild but the weakest cards on their own
+    pub fn jokers() -> Self {
+        Self::new("J23456789TQKA", 5).with_wildcards(Jokers('J'))
+    }
+
+    pub fn rank(&self, hand: &Hand) -> Result<Ranking> {
+        if hand.cards.len() != self.hand_size {
+            return Err(anyhow!(
+                "Hands have {} cards, but {} has {}",
+                self.hand_size,
+                hand,
+                hand.cards.len()
+            ));
+        }
+
+        let strengths = hand
+            .cards
+            .iter()
+            .map(|&c| {
+                self.order
+                    .iter()
+                    .position(|&o| o == c)
+                    .ok_or_else(|| anyhow!("Invalid card {} in {}", c, hand))
+            })
+            .collect::<Result<_>>()?;
+
+        let (wild, natural): (Vec<&char>, Vec<&char>) =
+            hand.cards.iter().partition(|&&c| self.wildcards.is_wild(c));
+        let groups = natural
+            .into_iter()
+            .counts()
+            .into_values()
+            .sorted()
+            .rev()
+            .collect::<Vec<_>>();
+
+        Ok(Ranking {
+            natural: HandType::from_groups(&groups),
+            r#type: HandType::from_groups(&self.wildcards.assign(groups, wild.len())),
+            strengths,
+            wildcards: wild.len(),
+            wildcard_name: self.wildcards.name().into(),
+        })
+    }
+
+    /// Sum of each hand's bid times its rank, from 1 for the weakest hand
+    pub fn total_winnings(&self, hands: &[Hand]) -> Result<usize> {
+        let rankings = hands
+            .iter()
+            .map(|h| Ok((self.rank(h)?, h.bid)))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(rankings
+            .into_iter()
+            .sorted()
+            .enumerate()
+            .map(|(i, (_, bid))| (i + 1) * bid)
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hand(cards: &str) -> Hand {
+        Hand {
+            cards: cards.chars().collect(),
+            bid: 0,
+        }
+    }
+
+    #[test]
+    fn explanations() {
+        let rules = Rules::jokers();
+        let explain = |cards| rules.rank(&hand(cards)).unwrap().to_string();
+
+        assert_eq!(explain("32T3K"), "one pair");
+        assert_eq!(explain("2233J"), "two pair + 1 joker → full house");
+        assert_eq!(explain("KTJJT"), "one pair + 2 jokers → four of a kind");
+        assert_eq!(explain("JJJJJ"), "high card + 5 jokers → five of a kind");
+    }
+
+    #[test]
+    fn configurable() {
+        let rules = Rules::new("ABC", 3).with_wildcards(Jokers('C'));
+
+        assert_eq!(rules.rank(&hand("ABB")).unwrap().r#type, HandType::OnePair);
+        assert_eq!(
+            rules.rank(&hand("BCB")).unwrap().r#type,
+            HandType::ThreeOfAKind
+        );
+        assert!(rules.rank(&hand("AB")).is_err());
+        assert!(rules.rank(&hand("ABD")).is_err());
+
+        // jokers are the weakest card, so lose ties
+        let rules = Rules::jokers();
+        assert!(rules.rank(&hand("JKKK2")).unwrap() < rules.rank(&hand("QQQQ2")).unwrap());
+    }
+
+    /// Trying every card in place of every joker never beats what the jokers
+    /// were assigned. Cards that aren't in a hand all make the same difference
+    /// to it, so only one of them needs trying.
+    #[test]
+    fn jokers_pick_the_best_type() {
+        let (cards, joker) = ("23456", 'J');
+        let jokers = Rules::new("J23456", 5).with_wildcards(Jokers(joker));
+        let plain = Rules::new("J23456", 5);
+
+        let alphabet = cards.chars().chain([joker]).collect::<Vec<_>>();
+        for dealt in (0..5).map(|_| alphabet.iter()).multi_cartesian_product() {
+            let dealt = dealt.into_iter().copied().collect::<String>();
+            let missing = cards.chars().find(|&c| !dealt.contains(c));
+            let substitutes = cards
+                .chars()
+                .filter(|&c| dealt.contains(c))
+                .chain(missing)
+                .collect::<Vec<_>>();
+
+            let best = dealt
+                .chars()
+                .map(|c| match c {
+                    c if c == joker => substitutes.clone(),
+                    c => vec![c],
+                })
+                .multi_cartesian_product()
+                .map(|substituted| plain.rank(&hand(&substituted.iter().collect::<String>())))
+                .map(|ranking| ranking.unwrap().r#type)
+                .max()
+                .unwrap();
+
+            assert_eq!(jokers.rank(&hand(&dealt)).unwrap().r#type, best, "{dealt}");
+        }
+    }
+}
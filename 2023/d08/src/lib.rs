@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::iter;
+
+use anyhow::{anyhow, Result};
+use aoc_cycle::detect_cycle_hashed;
+use aoc_geometry::Turn;
+use aoc_solution::Solution;
+use itertools::{
+    Either,
+    FoldWhile::{Continue, Done},
+    Itertools,
+};
+
+mod path;
+
+pub use path::{crt, first_common_end, Path};
+
+#[derive(Debug)]
+pub struct Map {
+    turns: Vec<Turn>,
+    nodes: HashMap<String, (String, String)>,
+}
+
+impl Map {
+    const START: &'static str = "AAA";
+    const END: &'static str = "ZZZ";
+
+    pub fn try_from_lines<I: IntoIterator<Item = String>>(lines: I) -> Result<Self> {
+        let mut lines = lines.into_iter();
+
+        let turns = lines
+            .next()
+            .ok_or(anyhow!("Missing turns"))?
+            .chars()
+            .map(|c| c.try_into())
+            .collect::<Result<Vec<_>>>()?;
+        if turns.is_empty() {
+            return Err(anyhow!("Missing turns"));
+        }
+
+        let nodes = lines
+            .skip(1)
+            .map(|line| {
+                let mut parts = line.split(" = ");
+                let key = parts.next().ok_or(anyhow!("Missing node key"))?.to_string();
+
+                parts = parts
+                    .next()
+                    .ok_or(anyhow!("Invalid node destinations"))?
+                    .split(", ");
+
+                let left = parts
+                    .next()
+                    .ok_or(anyhow!("Missing left destination"))?
+                    .trim_start_matches('(')
+                    .to_string();
+
+                let right = parts
+                    .next()
+                    .ok_or(anyhow!("Missing right destination"))?
+                    .trim_end_matches(')')
+                    .to_string();
+
+                Ok((key, (left, right)))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { turns, nodes })
+    }
+
+    fn next<'a>(&'a self, node: &str, turn: &Turn) -> &'a str {
+        match turn {
+            Turn::Left => &self.nodes[node].0,
+            Turn::Right => &self.nodes[node].1,
+        }
+    }
+
+    /// Number of steps to get from the start node to the first node
+    /// satisfying is_end
+    pub fn traverse<F: Fn(&str) -> bool>(&self, start: &str, is_end: F) -> usize {
+        self.turns
+            .iter()
+            .cycle()
+            .fold_while((0, start), |(i, node), turn| {
+                let next = self.next(node, turn);
+
+                if is_end(next) {
+                    Done((i + 1, next))
+                } else {
+                    Continue((i + 1, next))
+                }
+            })
+            .into_inner()
+            .0
+    }
+
+    /// Where following the turns from the start node lands on nodes
+    /// satisfying is_end, up to the end of the first time it loops
+    pub fn path<F: Fn(&str) -> bool>(&self, start: &str, is_end: F) -> Result<Path> {
+        // a state is a node and the index of the next turn
+        let step = |&(node, i): &(&str, usize)| {
+            (self.next(node, &self.turns[i]), (i + 1) % self.turns.len())
+        };
+        let cycle = detect_cycle_hashed((start, 0), usize::MAX, step)
+            .0
+            .ok_or_else(|| anyhow!("Path from {start} never loops"))?;
+
+        let ends = iter::successors(Some((start, 0)), |s| Some(step(s)))
+            .take(cycle.prefix + cycle.period)
+            .positions(|(node, _)| is_end(node));
+        let (tail_ends, cycle_ends) =
+            ends.partition_map(|steps| match steps.checked_sub(cycle.prefix) {
+                Some(offset) => Either::Right(offset),
+                None => Either::Left(steps),
+            });
+
+        Ok(Path {
+            tail: cycle.prefix,
+            cycle: cycle.period,
+            tail_ends,
+            cycle_ends,
+        })
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Map::try_from_lines(input.lines().map(String::from))
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        Ok(map.traverse(Map::START, |node| node == Map::END))
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        // find where each ghost's path lands on ending nodes, then where
+        // they all line up
+        let paths = map
+            .nodes
+            .keys()
+            .filter(|n| n.ends_with('A'))
+            .map(|n| map.path(n, |node| node.ends_with('Z')))
+            .collect::<Result<Vec<_>>>()?;
+        if paths.is_empty() {
+            return Err(anyhow!("No starting nodes"));
+        }
+
+        let steps = first_common_end(&paths)?
+            .ok_or(anyhow!("Ghosts are never all on ending nodes at once"))?;
+        steps
+            .try_into()
+            .map_err(|_| anyhow!("Too many steps: {steps}"))
+    }
+}
+
+aoc_solution::expected_answers!(Day08);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_turns() {
+        let err = Day08::parse("\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!(err.to_string(), "Missing turns");
+    }
+}
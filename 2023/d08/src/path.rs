@@ -0,0 +1,170 @@
+use anyhow::{anyhow, Result};
+use num::integer::{ExtendedGcd, Integer};
+
+/// Where a path through the map lands on end nodes. Following the turns from
+/// a node eventually loops, since there are only so many nodes and positions
+/// in the turns.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path {
+    /// Steps before the path starts looping
+    pub tail: usize,
+    /// Steps in each loop
+    pub cycle: usize,
+    /// Steps within the tail that land on an end node
+    pub tail_ends: Vec<usize>,
+    /// Distinct offsets into each loop that land on an end node
+    pub cycle_ends: Vec<usize>,
+}
+
+impl Path {
+    /// Whether the path is on an end node after some number of steps
+    pub fn is_end(&self, steps: usize) -> bool {
+        if steps < self.tail {
+            self.tail_ends.contains(&steps)
+        } else {
+            self.cycle_ends
+                .contains(&((steps - self.tail) % self.cycle))
+        }
+    }
+}
+
+/// Combine x ≡ a1 (mod m1) and x ≡ a2 (mod m2) into a single congruence
+/// modulo their least common multiple, or None if both can't hold at once or
+/// the numbers get too big. Unlike the textbook version, the moduli don't
+/// need to be coprime.
+pub fn crt((a1, m1): (i128, i128), (a2, m2): (i128, i128)) -> Option<(i128, i128)> {
+    let ExtendedGcd { gcd, x, .. } = m1.extended_gcd(&m2);
+    let diff = a2.checked_sub(a1)?;
+    if diff % gcd != 0 {
+        return None;
+    }
+
+    // m1 * x ≡ gcd (mod m2), so adding m1 * x * (a2 - a1) / gcd to a1 gets to
+    // a2 (mod m2) while staying at a1 (mod m1)
+    let m = m2 / gcd;
+    let k = (diff / gcd).rem_euclid(m).checked_mul(x.rem_euclid(m))? % m;
+    let lcm = m1.checked_mul(m)?;
+
+    Some((a1.checked_add(m1.checked_mul(k)?)?.rem_euclid(lcm), lcm))
+}
+
+/// Most combinations of one end per path worth trying
+const MAX_COMBINATIONS: usize = 1 << 20;
+
+/// The fewest steps after which every path is on an end node at the same
+/// time, or None if that never happens. Fails if there are too many
+/// combinations of ends to try, or the paths take too long to line up.
+pub fn first_common_end(paths: &[Path]) -> Result<Option<u128>> {
+    // before every path is looping, just check each step
+    let tail = paths.iter().map(|p| p.tail).max().unwrap_or(0);
+    if let Some(steps) = (0..tail).find(|&steps| paths.iter().all(|p| p.is_end(steps))) {
+        return Ok(Some(steps as u128));
+    }
+
+    // after that, each path is on an end node at steps matching one of its
+    // congruences, so combine every choice of one congruence per path. They
+    // all share the same modulus, the LCM of the cycles so far.
+    let mut congruences: Vec<(i128, i128)> = vec![(0, 1)];
+    for path in paths {
+        let Some(&(_, modulus)) = congruences.first() else {
+            return Ok(None);
+        };
+        let cycle = path.cycle as i128;
+        if modulus.checked_mul(cycle / modulus.gcd(&cycle)).is_none() {
+            return Err(anyhow!("Cycles are too long to line up"));
+        }
+        if congruences.len().saturating_mul(path.cycle_ends.len()) > MAX_COMBINATIONS {
+            return Err(anyhow!("Too many combinations of ends to try"));
+        }
+
+        congruences = congruences
+            .iter()
+            .flat_map(|&c| {
+                path.cycle_ends
+                    .iter()
+                    .filter_map(move |&o| crt(c, ((path.tail + o) as i128 % cycle, cycle)))
+            })
+            .collect();
+        congruences.sort_unstable();
+        congruences.dedup();
+    }
+
+    // the first step matching any of them, once every path is looping
+    let tail = tail as i128;
+    congruences
+        .into_iter()
+        .map(|(a, m)| {
+            if a >= tail {
+                Some(a)
+            } else {
+                Integer::div_ceil(&(tail - a), &m)
+                    .checked_mul(m)
+                    .and_then(|n| a.checked_add(n))
+            }
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| anyhow!("Too many steps to line up"))
+        .map(|steps| steps.into_iter().min().map(|steps| steps as u128))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn congruences() {
+        assert_eq!(crt((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(crt((1, 4), (3, 6)), Some((9, 12)));
+        assert_eq!(crt((1, 4), (2, 6)), None);
+        assert_eq!(crt((0, 1), (4, 7)), Some((4, 7)));
+
+        // the LCM doesn't fit
+        assert_eq!(crt((0, i128::MAX), (1, 2)), None);
+    }
+
+    #[test]
+    fn common_ends() {
+        let path = |tail, cycle, tail_ends: &[usize], cycle_ends: &[usize]| Path {
+            tail,
+            cycle,
+            tail_ends: tail_ends.to_vec(),
+            cycle_ends: cycle_ends.to_vec(),
+        };
+
+        let brute_force = |paths: &[Path]| {
+            (0..1000).find(|&steps| paths.iter().all(|p| p.is_end(steps as usize)))
+        };
+
+        for paths in [
+            // clean loops, aligned with the start
+            [path(1, 2, &[], &[1]), path(1, 3, &[], &[2])],
+            // tails of different lengths, and loops that aren't coprime
+            [path(3, 4, &[1], &[1, 3]), path(5, 6, &[], &[3])],
+            // at an end at the same time before both are looping
+            [path(4, 3, &[2], &[]), path(0, 2, &[], &[0])],
+            // only ever at an end on odd and even steps respectively
+            [path(0, 2, &[], &[1]), path(0, 4, &[], &[0, 2])],
+        ] {
+            assert_eq!(first_common_end(&paths).unwrap(), brute_force(&paths));
+        }
+
+        let offset = [path(3, 4, &[1], &[1, 3]), path(5, 6, &[], &[3])];
+        assert_eq!(first_common_end(&offset).unwrap(), Some(8));
+
+        // coprime cycles whose LCM doesn't fit
+        let n = (1 << 62) + 1;
+        let long = [n, n + 1, n + 2].map(|cycle| path(0, cycle, &[], &[1]));
+        assert_eq!(
+            first_common_end(&long).unwrap_err().to_string(),
+            "Cycles are too long to line up"
+        );
+
+        // an end on every step of long cycles
+        let ends = (0..2000).collect::<Vec<_>>();
+        let busy = [path(0, 2000, &[], &ends), path(0, 2001, &[], &ends)];
+        assert_eq!(
+            first_common_end(&busy).unwrap_err().to_string(),
+            "Too many combinations of ends to try"
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Error, Result};
+use aoc_solution::Solution;
+use num::{BigRational, ToPrimitive};
+
+#[derive(Debug)]
+pub struct History {
+    history: Vec<isize>,
+}
+
+impl History {
+    /// Degree of the polynomial the sequence follows, or None if it doesn't
+    /// follow one. Any n values fit a polynomial of degree n - 1, so a degree
+    /// only counts if the differences are constant for at least two values.
+    pub fn degree(&self) -> Option<usize> {
+        let mut differences = self.history.clone();
+        for degree in 0.. {
+            if differences.len() < 2 {
+                return None;
+            }
+            if differences.windows(2).all(|w| w[0] == w[1]) {
+                return Some(degree);
+            }
+            differences = differences.windows(2).map(|w| w[1] - w[0]).collect();
+        }
+
+        unreachable!()
+    }
+
+    /// Predict the value at any index of the sequence, where 0 is the first
+    /// value and negative indices come before it. Uses Lagrange interpolation
+    /// through the first degree + 1 values, in exact arithmetic.
+    pub fn predict(&self, index: isize) -> Result<BigRational> {
+        let degree = self
+            .degree()
+            .ok_or_else(|| anyhow!("Not a polynomial sequence: {:?}", self.history))?;
+
+        let x = BigRational::from_integer(index.into());
+        let points = (0..=degree)
+            .map(|i| (BigRational::from_integer(i.into()), self.history[i]))
+            .collect::<Vec<_>>();
+
+        Ok(points
+            .iter()
+            .map(|(xj, yj)| {
+                points
+                    .iter()
+                    .filter(|(xm, _)| xm != xj)
+                    .fold(BigRational::from_integer((*yj).into()), |term, (xm, _)| {
+                        term * (&x - xm) / (xj - xm)
+                    })
+            })
+            .sum())
+    }
+
+    fn predict_integer(&self, index: isize) -> Result<isize> {
+        let value = self.predict(index)?;
+        value
+            .is_integer()
+            .then(|| value.to_integer().to_isize())
+            .flatten()
+            .ok_or_else(|| anyhow!("Prediction {value} at {index} isn't an isize"))
+    }
+
+    /// Predict the next value of the sequence
+    pub fn extrapolate(&self) -> Result<isize> {
+        self.predict_integer(self.history.len() as isize)
+    }
+
+    /// Predict the value that would have come before the sequence
+    pub fn extrapolate_rev(&self) -> Result<isize> {
+        self.predict_integer(-1)
+    }
+}
+
+impl FromStr for History {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let history = s
+            .split(' ')
+            .map(|x| x.parse().context("Invalid history value"))
+            .collect::<Result<_>>()?;
+
+        Ok(Self { history })
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<History>;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.parse()
+                    .with_context(|| format!("Invalid history on line {i}"))
+            })
+            .collect()
+    }
+
+    fn part1(histories: &Self::Input) -> Result<isize> {
+        histories.iter().map(History::extrapolate).sum()
+    }
+
+    fn part2(histories: &Self::Input) -> Result<isize> {
+        histories.iter().map(History::extrapolate_rev).sum()
+    }
+}
+
+aoc_solution::expected_answers!(Day09);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(values: &[isize]) -> History {
+        History {
+            history: values.to_vec(),
+        }
+    }
+
+    #[test]
+    fn predictions() {
+        let squares = history(&[0, 1, 4, 9, 16]);
+        assert_eq!(squares.degree(), Some(2));
+        for index in -10..10 {
+            let expected = BigRational::from_integer((index * index).into());
+            assert_eq!(squares.predict(index).unwrap(), expected);
+        }
+
+        let constant = history(&[7, 7]);
+        assert_eq!(constant.degree(), Some(0));
+        assert_eq!(constant.extrapolate_rev().unwrap(), 7);
+
+        assert_eq!(
+            history(&[10, 13, 16, 21, 30, 45]).extrapolate().unwrap(),
+            68
+        );
+        assert_eq!(
+            history(&[10, 13, 16, 21, 30, 45])
+                .extrapolate_rev()
+                .unwrap(),
+            5
+        );
+    }
+
+    #[test]
+    fn not_polynomial() {
+        for values in [&[1, 2, 4, 8, 16, 32][..], &[3, 5], &[1]] {
+            assert_eq!(history(values).degree(), None);
+            assert!(history(values).extrapolate().is_err());
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use anyhow::{anyhow, Error, Result};
+use aoc_geometry::{interior_points, Direction, Point};
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+
+#[derive(Debug)]
+pub enum Cell {
+    Ground,
+    Pipe(Direction, Direction),
+    /// Placeholder until replaced with Pipe
+    Start,
+}
+
+impl Cell {
+    /// Try to enter a pipe from a given direction
+    /// returns exit direction
+    fn try_enter(&self, dir: Direction) -> Option<Direction> {
+        match self {
+            Self::Ground => None,
+            &Self::Pipe(end1, end2) => match dir {
+                _ if dir == end1 => Some(end2),
+                _ if dir == end2 => Some(end1),
+                _ => None,
+            },
+            // Exit direction doesn't matter
+            Self::Start => Some(dir),
+        }
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '.' => Ok(Self::Ground),
+            '|' => Ok(Self::Pipe(Direction::Up, Direction::Down)),
+            '-' => Ok(Self::Pipe(Direction::Right, Direction::Left)),
+            'L' => Ok(Self::Pipe(Direction::Up, Direction::Right)),
+            'J' => Ok(Self::Pipe(Direction::Up, Direction::Left)),
+            '7' => Ok(Self::Pipe(Direction::Down, Direction::Left)),
+            'F' => Ok(Self::Pipe(Direction::Down, Direction::Right)),
+            'S' => Ok(Self::Start),
+            _ => Err(anyhow!("Invalid cell: {}", c)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Sketch {
+    grid: Grid<Cell>,
+    start: Point<usize>,
+    pipe_loop: Vec<Point<usize>>,
+}
+
+impl Sketch {
+    pub fn new(grid: Grid<Cell>) -> Result<Self> {
+        let (y, x) = grid
+            .position(|c| matches!(c, Cell::Start))
+            .ok_or(anyhow!("Missing start cell"))?;
+
+        let mut sketch = Self {
+            grid,
+            start: Point::new(x, y),
+            pipe_loop: Vec::new(),
+        };
+
+        sketch.resolve_start()?;
+
+        Ok(sketch)
+    }
+
+    pub fn max_distance_from_start(&self) -> usize {
+        // loop duplicates start at end
+        let loop_len = self.pipe_loop.len() - 1;
+        loop_len / 2 + loop_len % 2
+    }
+
+    pub fn inner_points(&self) -> usize {
+        interior_points(&self.pipe_loop)
+    }
+
+    fn get<'a>(&'a self, loc: &Point<usize>) -> &'a Cell {
+        &self.grid[(loc.y, loc.x)]
+    }
+
+    fn set_start(&mut self, pipe: Cell) {
+        self.grid.set(self.start.y, self.start.x, pipe);
+    }
+
+    /// Try to move from a given point in a given direction.
+    /// If the move is impossible, None is returned.
+    /// Otherwise, the return value contains a tuple of the new location
+    /// and exit direction.
+    fn try_move(&self, loc: Point<usize>, dir: Direction) -> Option<(Point<usize>, Direction)> {
+        let next = loc
+            .checked_step(dir)
+            .filter(|next| self.grid.contains(next.y, next.x));
+
+        next.and_then(|next| {
+            self.get(&next)
+                .try_enter(dir.opposite())
+                .map(|exit| (next, exit))
+        })
+    }
+
+    /// Replace the Cell::Start placeholder with a resolved Pipe
+    fn resolve_start(&mut self) -> Result<()> {
+        for start_dir in Direction::ALL {
+            let mut pipe_loop = vec![self.start];
+            let mut curr = self.try_move(self.start, start_dir);
+            while let Some((loc, dir)) = curr {
+                // loop will duplicate start at end
+                pipe_loop.push(loc);
+                if loc == self.start {
+                    self.set_start(Cell::Pipe(start_dir, dir.opposite()));
+                    self.pipe_loop = pipe_loop;
+                    return Ok(());
+                }
+
+                curr = self.try_move(loc, dir);
+            }
+        }
+
+        Err(anyhow!("Could not resolve start"))
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Sketch;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Sketch::new(input.parse()?)
+    }
+
+    fn part1(sketch: &Self::Input) -> Result<usize> {
+        Ok(sketch.max_distance_from_start())
+    }
+
+    fn part2(sketch: &Self::Input) -> Result<usize> {
+        Ok(sketch.inner_points())
+    }
+}
+
+aoc_solution::expected_answers!(Day10);
@@ -0,0 +1,195 @@
+use anyhow::{anyhow, Result};
+use aoc_solution::Solution;
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Galaxy {
+    x: usize,
+    y: usize,
+}
+
+impl Galaxy {
+    pub fn shortest_path(&self, other: &Galaxy) -> usize {
+        // (self.x - other.x).abs() + (self.y - other.y).abs()
+        self.x
+            .checked_sub(other.x)
+            .unwrap_or_else(|| other.x - self.x)
+            + self
+                .y
+                .checked_sub(other.y)
+                .unwrap_or_else(|| other.y - self.y)
+    }
+}
+
+/// Sum of the distances between every pair of positions along one axis. In
+/// sorted order, each position is that far past all the ones before it.
+fn sum_distances(mut positions: Vec<usize>) -> usize {
+    positions.sort_unstable();
+    positions
+        .iter()
+        .enumerate()
+        .fold((0, 0), |(sum, before), (i, &p)| {
+            (sum + i * p - before, before + p)
+        })
+        .0
+}
+
+#[derive(Clone, Debug)]
+pub struct Image {
+    pub galaxies: Vec<Galaxy>,
+    /// How many empty rows come before each row
+    empty_rows: Vec<usize>,
+    /// How many empty columns come before each column
+    empty_cols: Vec<usize>,
+}
+
+impl Image {
+    pub fn try_from_lines<I: IntoIterator<Item = String>>(lines: I) -> Result<Self> {
+        let mut galaxies = Vec::new();
+
+        let mut lines = lines.into_iter().peekable();
+        let cols = lines
+            .peek()
+            .ok_or(anyhow!("Missing input"))?
+            .chars()
+            .count();
+
+        let mut rows = 0;
+        for line in lines {
+            let width = line.chars().count();
+            if width != cols {
+                return Err(anyhow!(
+                    "Row {rows} has {width} columns, but the first has {cols}"
+                ));
+            }
+
+            line.chars()
+                .enumerate()
+                .filter_map(|(col, c)| (c == '#').then_some(Galaxy { x: col, y: rows }))
+                .for_each(|g| galaxies.push(g));
+            rows += 1;
+        }
+
+        // count the empty rows and columns before each one
+        let mut occupied_rows = vec![false; rows];
+        let mut occupied_cols = vec![false; cols];
+        for g in &galaxies {
+            occupied_rows[g.y] = true;
+            occupied_cols[g.x] = true;
+        }
+        let prefix_sums = |occupied: Vec<bool>| {
+            occupied
+                .into_iter()
+                .scan(0, |empty, occupied| {
+                    let before = *empty;
+                    *empty += usize::from(!occupied);
+                    Some(before)
+                })
+                .collect()
+        };
+
+        Ok(Image {
+            galaxies,
+            empty_rows: prefix_sums(occupied_rows),
+            empty_cols: prefix_sums(occupied_cols),
+        })
+    }
+
+    /// Where a galaxy ends up when every empty row is replaced by row_factor
+    /// empty rows, and every empty column by col_factor empty columns
+    pub fn expand(&self, galaxy: &Galaxy, row_factor: usize, col_factor: usize) -> Galaxy {
+        let (rows, cols) = (self.empty_rows[galaxy.y], self.empty_cols[galaxy.x]);
+        Galaxy {
+            x: galaxy.x - cols + cols * col_factor,
+            y: galaxy.y - rows + rows * row_factor,
+        }
+    }
+
+    /// Every galaxy, after expanding the image
+    pub fn expanded(
+        &self,
+        row_factor: usize,
+        col_factor: usize,
+    ) -> impl Iterator<Item = Galaxy> + '_ {
+        self.galaxies
+            .iter()
+            .map(move |g| self.expand(g, row_factor, col_factor))
+    }
+
+    pub fn galaxy_pairs<'a>(&'a self) -> impl Iterator<Item = (&'a Galaxy, &'a Galaxy)> + 'a {
+        self.galaxies.iter().combinations(2).map(|c| (c[0], c[1]))
+    }
+
+    /// Sum of the shortest paths between every pair of galaxies after
+    /// expanding the image. Paths are Manhattan distances, so each axis can
+    /// be summed on its own.
+    pub fn sum_shortest_paths(&self, row_factor: usize, col_factor: usize) -> usize {
+        let (xs, ys) = self
+            .expanded(row_factor, col_factor)
+            .map(|g| (g.x, g.y))
+            .unzip();
+        sum_distances(xs) + sum_distances(ys)
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Image;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Image::try_from_lines(input.lines().map(String::from))
+    }
+
+    fn part1(image: &Self::Input) -> Result<usize> {
+        Ok(image.sum_shortest_paths(2, 2))
+    }
+
+    fn part2(image: &Self::Input) -> Result<usize> {
+        Ok(image.sum_shortest_paths(1_000_000, 1_000_000))
+    }
+}
+
+aoc_solution::expected_answers!(Day11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn expansion() {
+        let image = Day11::parse(EXAMPLE).unwrap();
+
+        for (rows, cols) in [(1, 1), (2, 2), (10, 10), (100, 100), (0, 3), (5, 1)] {
+            let galaxies = image.expanded(rows, cols).collect::<Vec<_>>();
+            let brute_force = galaxies
+                .iter()
+                .combinations(2)
+                .map(|c| c[0].shortest_path(c[1]))
+                .sum::<usize>();
+            assert_eq!(image.sum_shortest_paths(rows, cols), brute_force);
+        }
+
+        assert_eq!(image.sum_shortest_paths(2, 2), 374);
+        assert_eq!(image.sum_shortest_paths(10, 10), 1030);
+        assert_eq!(image.sum_shortest_paths(100, 100), 8410);
+
+        // the first galaxy is after one empty column and no empty rows
+        assert_eq!(
+            image.expand(&image.galaxies[0], 3, 5),
+            Galaxy { x: 3 + 4, y: 0 }
+        );
+    }
+
+    #[test]
+    fn ragged() {
+        assert_eq!(
+            Day11::parse("#..\n.#..#\n").unwrap_err().to_string(),
+            "Row 1 has 5 columns, but the first has 3"
+        );
+    }
+}
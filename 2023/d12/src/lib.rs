@@ -1,14 +1,11 @@
 use std::collections::HashMap;
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::iter;
 use std::str::FromStr;
 
 use anyhow::{anyhow, Context, Error, Result};
+use aoc_solution::Solution;
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Spring {
+pub enum Spring {
     Operational,
     Broken,
     Unknown,
@@ -28,7 +25,7 @@ impl TryFrom<char> for Spring {
 }
 
 #[derive(Debug)]
-struct Row {
+pub struct Row {
     springs: Vec<Spring>,
     groups: Vec<usize>,
 }
@@ -99,9 +96,11 @@ impl Row {
                         // if we can match a group, add possibilities for operational
                         // (e.g. start new group)
                         broken_possibilities
-                            + (groups[0] == x)
-                                .then(|| inner(memo, &springs[1..], &groups[1..], 0))
-                                .unwrap_or_default()
+                            + if groups[0] == x {
+                                inner(memo, &springs[1..], &groups[1..], 0)
+                            } else {
+                                Default::default()
+                            }
                     }
                 },
             };
@@ -112,6 +111,22 @@ impl Row {
 
         inner(&mut HashMap::new(), &self.springs, &self.groups, 0)
     }
+
+    /// Unfold the row, repeating springs (separated by Unknown) and groups n times
+    pub fn unfold(&self, n: usize) -> Row {
+        let springs = itertools::intersperse(
+            std::iter::repeat_n(self.springs.clone(), n),
+            vec![Spring::Unknown],
+        )
+        .flatten()
+        .collect();
+
+        let groups = std::iter::repeat_n(self.groups.clone(), n)
+            .flatten()
+            .collect();
+
+        Row { springs, groups }
+    }
 }
 
 impl FromStr for Row {
@@ -125,45 +140,40 @@ impl FromStr for Row {
             .ok_or(anyhow!("Missing springs"))?
             .chars()
             .map(|c| c.try_into())
-            .collect::<Result<Vec<_>>>()?;
-
-        // unfold
-        let springs = itertools::intersperse(iter::repeat(springs).take(5), vec![Spring::Unknown])
-            .flatten()
-            .collect();
+            .collect::<Result<_>>()?;
 
         let groups = parts
             .next()
             .ok_or(anyhow!("Missing group lengths"))?
             .split(',')
-            .map(|c| c.parse().context("Invalid group length"));
-
-        // unfold
-        let groups = iter::repeat(groups)
-            .take(5)
-            .flatten()
+            .map(|c| c.parse().context("Invalid group length"))
             .collect::<Result<_>>()?;
 
         Ok(Row { springs, groups })
     }
 }
 
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-    let input = File::open(&args[1])?;
-    let reader = io::BufReader::new(input);
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Row>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let sum = reader
-        .lines()
-        .filter_map(|line| line.ok())
-        .map(|line| {
-            line.parse()
-                .context("Invalid Row")
-                .and_then(|r: Row| Ok(r.possible_arrangements()))
-        })
-        .sum::<Result<usize>>()?;
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.parse().context("Invalid Row"))
+            .collect()
+    }
 
-    println!("Sum of possible arrangements: {}", sum);
+    fn part1(rows: &Self::Input) -> Result<usize> {
+        Ok(rows.iter().map(Row::possible_arrangements).sum())
+    }
 
-    Ok(())
+    fn part2(rows: &Self::Input) -> Result<usize> {
+        Ok(rows.iter().map(|r| r.unfold(5).possible_arrangements()).sum())
+    }
 }
+
+aoc_solution::expected_answers!(Day12);
@@ -0,0 +1,90 @@
+use anyhow::{anyhow, Error, Result};
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Cell {
+    Ash,
+    Rock,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '.' => Ok(Self::Ash),
+            '#' => Ok(Self::Rock),
+            c => Err(anyhow!("Invalid cell: {}", c)),
+        }
+    }
+}
+
+/// There are only two possible values so treat a row or col as a binary number
+fn to_bits<'a>(cells: impl Iterator<Item = &'a Cell>) -> u32 {
+    cells.fold(0, |bits, c| bits << 1 | (*c == Cell::Rock) as u32)
+}
+
+#[derive(Debug, Default)]
+pub struct Pattern {
+    rows: Vec<u32>,
+    cols: Vec<u32>,
+}
+
+impl Pattern {
+    pub fn new(grid: &Grid<Cell>) -> Self {
+        Self {
+            rows: grid.iter_rows().map(|r| to_bits(r.iter())).collect(),
+            cols: grid.iter_cols().map(to_bits).collect(),
+        }
+    }
+
+    /// Summarize the line of reflection, which is where exactly
+    /// `smudges` cells differ from their mirror image
+    pub fn summarize(&self, smudges: u32) -> usize {
+        let find_reflect = |xs: &[u32]| {
+            for i in 1..xs.len() {
+                if xs[..i]
+                    .iter()
+                    .rev()
+                    .zip(xs[i..].iter())
+                    .map(|(a, b)| (a ^ b).count_ones())
+                    .sum::<u32>()
+                    == smudges
+                {
+                    return i;
+                }
+            }
+
+            0
+        };
+
+        find_reflect(&self.rows) * 100 + find_reflect(&self.cols)
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pattern>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // patterns are separated by empty lines
+        input
+            .split("\n\n")
+            .map(|group| Ok(Pattern::new(&group.parse()?)))
+            .collect()
+    }
+
+    fn part1(patterns: &Self::Input) -> Result<usize> {
+        Ok(patterns.iter().map(|p| p.summarize(0)).sum())
+    }
+
+    fn part2(patterns: &Self::Input) -> Result<usize> {
+        Ok(patterns.iter().map(|p| p.summarize(1)).sum())
+    }
+}
+
+aoc_solution::expected_answers!(Day13);
@@ -0,0 +1,170 @@
+use std::hash::Hash;
+
+use anyhow::{anyhow, Error, Result};
+use aoc_cycle::detect_cycle_hashed;
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+use aoc_visualize::{Frame, NoRecorder, Pixel, Recorder, Rgb};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Cell {
+    Empty,
+    Round,
+    Cube,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = Error;
+
+    fn try_from(c: char) -> Result<Self> {
+        match c {
+            '.' => Ok(Self::Empty),
+            'O' => Ok(Self::Round),
+            '#' => Ok(Self::Cube),
+            c => Err(anyhow!("Invalid cell: {}", c)),
+        }
+    }
+}
+
+impl Cell {
+    fn pixel(&self) -> Pixel {
+        match self {
+            Self::Empty => Pixel::new('.', Rgb::gray(32)),
+            Self::Round => Pixel::new('O', Rgb::YELLOW),
+            Self::Cube => Pixel::new('#', Rgb::GRAY),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Dish {
+    cells: Grid<Cell>,
+}
+
+impl Dish {
+    pub fn new(cells: Grid<Cell>) -> Self {
+        Self { cells }
+    }
+
+    /// Draw the dish with north up, after it's been rotated right some number of times
+    fn frame(&self, rotations: usize) -> Frame {
+        (0..rotations % 4).fold(self.cells.map(Cell::pixel), |frame, _| frame.rotate_left())
+    }
+
+    pub fn tilt_cycles(&mut self, n: usize, recorder: &mut dyn Recorder) {
+        recorder.draw(|| self.frame(0));
+
+        // spinning is slow and recorded, so only do it once for each state
+        self.cells = detect_cycle_hashed(self.cells.clone(), n, |cells| {
+            let mut dish = Dish::new(cells.clone());
+            dish.tilt_cycle(recorder);
+            dish.cells
+        })
+        .1;
+    }
+
+    /// Tilt north, west, south then east, recording the dish after each tilt
+    pub fn tilt_cycle(&mut self, recorder: &mut dyn Recorder) {
+        for rotations in 0..4 {
+            self.tilt();
+            recorder.draw(|| self.frame(rotations));
+            self.rotate_right();
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.cells.rows()
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cells.cols()
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> &Cell {
+        &self.cells[(row, col)]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, cell: Cell) {
+        self.cells[(row, col)] = cell;
+    }
+
+    pub fn rotate_right(&mut self) {
+        self.cells = self.cells.rotate_right();
+    }
+
+    pub fn tilt(&mut self) {
+        for col in 0..self.cols() {
+            for row in 1..self.rows() {
+                if self.get(row, col) == &Cell::Round {
+                    let new_row = (1..=row)
+                        .filter_map(|i| match self.get(row - i, col) {
+                            Cell::Empty => None,
+                            _ => Some(row - i + 1),
+                        })
+                        .next()
+                        .unwrap_or_default();
+
+                    if new_row != row {
+                        self.set(row, col, Cell::Empty);
+                        self.set(new_row, col, Cell::Round);
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn total_load(&self) -> usize {
+        self.cells
+            .enumerate()
+            .map(|((row, _), c)| match c {
+                Cell::Empty | Cell::Cube => 0,
+                Cell::Round => self.rows() - row,
+            })
+            .sum()
+    }
+}
+
+const CYCLES: usize = 1_000_000_000;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Dish;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Dish::new(input.parse()?))
+    }
+
+    fn part1(dish: &Self::Input) -> Result<usize> {
+        let mut dish = dish.clone();
+        dish.tilt();
+
+        Ok(dish.total_load())
+    }
+
+    fn part2(dish: &Self::Input) -> Result<usize> {
+        let mut dish = dish.clone();
+        dish.tilt_cycles(CYCLES, &mut NoRecorder);
+
+        Ok(dish.total_load())
+    }
+
+    fn visualize(part: u8, dish: &Self::Input, recorder: &mut dyn Recorder) -> Result<()> {
+        let mut dish = dish.clone();
+        match part {
+            1 => {
+                recorder.draw(|| dish.frame(0));
+                dish.tilt();
+                recorder.draw(|| dish.frame(0));
+            }
+            2 => dish.tilt_cycles(CYCLES, recorder),
+            _ => return Err(anyhow!("Invalid part: {part}")),
+        }
+
+        Ok(())
+    }
+}
+
+aoc_solution::expected_answers!(Day14);
@@ -0,0 +1,203 @@
+use std::borrow::Borrow;
+use std::hash::{BuildHasherDefault, Hasher};
+
+/// The Holiday ASCII String Helper algorithm: for each byte, add it, multiply
+/// by 17 and keep the remainder mod 256
+#[derive(Clone, Copy, Debug, Default)]
+pub struct HolidayHasher {
+    state: u8,
+}
+
+impl Hasher for HolidayHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.state = self.state.wrapping_add(b).wrapping_mul(17);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.state as u64
+    }
+}
+
+/// For std collections. `Hash` for `str` adds a 0xff terminator after the
+/// bytes, so strings won't hash to the same values as in the puzzle.
+pub type BuildHolidayHasher = BuildHasherDefault<HolidayHasher>;
+
+/// HASH of a label's bytes, from 0 to 255
+pub fn hash(label: &[u8]) -> usize {
+    let mut hasher = HolidayHasher::default();
+    hasher.write(label);
+    hasher.finish() as usize
+}
+
+/// The Holiday ASCII String Helper Manual Arrangement Procedure: a map with a
+/// box for each possible HASH, where each box keeps its entries in the order
+/// they were first inserted
+#[derive(Clone, Debug)]
+pub struct HolidayHashMap<K, V> {
+    boxes: Vec<Vec<(K, V)>>,
+    len: usize,
+}
+
+impl<K, V> Default for HolidayHashMap<K, V> {
+    fn default() -> Self {
+        Self {
+            boxes: (0..Self::BOXES).map(|_| Vec::new()).collect(),
+            len: 0,
+        }
+    }
+}
+
+impl<K, V> HolidayHashMap<K, V> {
+    pub const BOXES: usize = 256;
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The contents of each box, in order
+    pub fn boxes(&self) -> impl Iterator<Item = &[(K, V)]> {
+        self.boxes.iter().map(Vec::as_slice)
+    }
+
+    /// Every entry, box by box
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.boxes.iter().flatten().map(|(k, v)| (k, v))
+    }
+}
+
+impl<K: AsRef<[u8]> + Eq, V> HolidayHashMap<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index of the box a key goes in, from the HASH of its label
+    pub fn box_of<Q: AsRef<[u8]> + ?Sized>(key: &Q) -> usize {
+        hash(key.as_ref())
+    }
+
+    fn position<Q>(&self, key: &Q) -> (usize, Option<usize>)
+    where
+        K: Borrow<Q>,
+        Q: AsRef<[u8]> + Eq + ?Sized,
+    {
+        let b = Self::box_of(key);
+        (b, self.boxes[b].iter().position(|(k, _)| k.borrow() == key))
+    }
+
+    /// Insert a value, returning the one it replaced. A replaced value keeps
+    /// its place in the box; anything new goes at the back.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.position(&key) {
+            (b, Some(i)) => Some(std::mem::replace(&mut self.boxes[b][i].1, value)),
+            (b, None) => {
+                self.boxes[b].push((key, value));
+                self.len += 1;
+                None
+            }
+        }
+    }
+
+    /// Remove a key, moving everything behind it in its box forward
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: AsRef<[u8]> + Eq + ?Sized,
+    {
+        let (b, i) = self.position(key);
+        let (_, value) = self.boxes[b].remove(i?);
+        self.len -= 1;
+        Some(value)
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: AsRef<[u8]> + Eq + ?Sized,
+    {
+        let (b, i) = self.position(key);
+        Some(&self.boxes[b][i?].1)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: AsRef<[u8]> + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+}
+
+impl<K: AsRef<[u8]> + Eq, V> Extend<(K, V)> for HolidayHashMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+impl<K: AsRef<[u8]> + Eq, V> FromIterator<(K, V)> for HolidayHashMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::hash::BuildHasher;
+
+    #[test]
+    fn hasher() {
+        assert_eq!(hash(b"HASH"), 52);
+        assert_eq!(HolidayHashMap::<String, u32>::box_of("rn"), 0);
+        assert_eq!(
+            HolidayHashMap::<String, u32>::box_of(&String::from("pc")),
+            3
+        );
+
+        // every byte counts, even ones that can't appear in labels
+        assert_eq!(BuildHolidayHasher::default().hash_one(255u8), 239);
+
+        // backing a std HashMap, which doesn't care how good the hash is
+        let mut map: HashMap<String, u32, BuildHolidayHasher> = HashMap::default();
+        map.extend([("rn".into(), 1), ("cm".into(), 2), ("qp".into(), 3)]);
+        map.insert("rn".into(), 4);
+        map.remove("cm");
+        assert_eq!(map.get("rn"), Some(&4));
+        assert_eq!(map.get("cm"), None);
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn map() {
+        // rn and cm both go in box 0
+        let mut map = [("rn", 1), ("cm", 2), ("qp", 3)]
+            .into_iter()
+            .collect::<HolidayHashMap<_, _>>();
+        assert_eq!(map.len(), 3);
+
+        assert_eq!(map.insert("rn", 4), Some(1));
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            [(&"rn", &4), (&"cm", &2), (&"qp", &3)]
+        );
+
+        assert_eq!(map.remove("rn"), Some(4));
+        assert_eq!(map.remove("rn"), None);
+        assert_eq!(map.get("cm"), Some(&2));
+        assert!(!map.contains_key("rn"));
+
+        map.extend([("rn", 5)]);
+        assert_eq!(map.boxes().next().unwrap(), [("cm", 2), ("rn", 5)]);
+        assert_eq!(map.len(), 3);
+    }
+}
@@ -0,0 +1,93 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Error, Result};
+use aoc_solution::Solution;
+
+mod hashmap;
+
+pub use hashmap::{hash, BuildHolidayHasher, HolidayHashMap, HolidayHasher};
+
+#[derive(Debug)]
+enum Op {
+    Assign(u32),
+    Remove,
+}
+
+#[derive(Debug)]
+struct Step {
+    label: String,
+    op: Op,
+}
+
+impl FromStr for Step {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (label, op) = if s.contains('=') {
+            let mut parts = s.split('=');
+            let label = parts.next().ok_or(anyhow!("Missing label"))?.into();
+            let focal_length = parts
+                .next()
+                .ok_or(anyhow!("Missing focal length"))?
+                .parse()
+                .context("Invalid focal length")?;
+            (label, Op::Assign(focal_length))
+        } else {
+            let label = s.strip_suffix('-').ok_or(anyhow!("Invalid step"))?.into();
+            (label, Op::Remove)
+        };
+
+        Ok(Self { label, op })
+    }
+}
+
+/// Sum of every lens' box number times its slot number times its focal length
+fn focusing_power(boxes: &HolidayHashMap<String, u32>) -> usize {
+    boxes
+        .boxes()
+        .enumerate()
+        .flat_map(|(i, lenses)| {
+            lenses
+                .iter()
+                .enumerate()
+                .map(move |(j, (_, focal_length))| (i + 1) * (j + 1) * *focal_length as usize)
+        })
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let line = input.lines().next().ok_or(anyhow!("Missing input"))?;
+
+        Ok(line.split(',').map(String::from).collect())
+    }
+
+    fn part1(steps: &Self::Input) -> Result<usize> {
+        Ok(steps.iter().map(|s| hash(s.as_bytes())).sum())
+    }
+
+    fn part2(steps: &Self::Input) -> Result<usize> {
+        let mut boxes = HolidayHashMap::new();
+
+        for step in steps
+            .iter()
+            .map(|s| s.parse::<Step>().context("Invalid step"))
+        {
+            let step = step?;
+            match step.op {
+                Op::Assign(focal_length) => boxes.insert(step.label, focal_length),
+                Op::Remove => boxes.remove(&step.label),
+            };
+        }
+
+        Ok(focusing_power(&boxes))
+    }
+}
+
+aoc_solution::expected_answers!(Day15);
@@ -1,11 +1,11 @@
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-
 use anyhow::{anyhow, Error, Result};
+use aoc_geometry::Direction;
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+use aoc_visualize::{Frame, NoRecorder, Pixel, Recorder, Rgb};
 
 #[derive(Debug)]
-enum Cell {
+pub enum Cell {
     Empty,
     MirrorFwd,
     MirrorBack,
@@ -28,42 +28,31 @@ impl TryFrom<char> for Cell {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+impl Cell {
+    pub fn glyph(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::MirrorFwd => '/',
+            Self::MirrorBack => '\\',
+            Self::SplitterHoriz => '-',
+            Self::SplitterVert => '|',
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
-struct Photon {
+pub struct Photon {
     row: usize,
     col: usize,
     dir: Direction,
 }
 
 impl Photon {
-    /// (Maybe) advance photon in its current direction
-    pub fn advance(self, max_row: usize, max_col: usize) -> Option<Self> {
-        match self.dir {
-            Direction::Up => (self.row > 0).then(|| Self {
-                row: self.row - 1,
-                ..self
-            }),
-            Direction::Down => (self.row < max_row).then(|| Self {
-                row: self.row + 1,
-                ..self
-            }),
-            Direction::Left => (self.col > 0).then(|| Self {
-                col: self.col - 1,
-                ..self
-            }),
-            Direction::Right => (self.col < max_col).then(|| Self {
-                col: self.col + 1,
-                ..self
-            }),
-        }
+    /// (Maybe) advance photon in its current direction, staying inside the grid
+    pub fn advance<T>(self, grid: &Grid<T>) -> Option<Self> {
+        let (dc, dr) = self.dir.delta();
+        grid.offset(self.row, self.col, dr, dc)
+            .map(|(row, col)| Self { row, col, ..self })
     }
 
     /// Change photon direction
@@ -73,64 +62,70 @@ impl Photon {
 }
 
 #[derive(Debug)]
-struct Grid {
-    cells: Vec<Cell>,
-    rows: usize,
-    cols: usize,
+pub struct Contraption {
+    cells: Grid<Cell>,
 }
 
-impl Grid {
-    pub fn try_from_lines<I: IntoIterator<Item = String>>(lines: I) -> Result<Self> {
-        let mut lines = lines.into_iter().peekable();
-        let cols = lines
-            .peek()
-            .ok_or(anyhow!("Missing input"))?
-            .chars()
-            .count();
-
-        let cells = lines
-            .flat_map(|line| line.chars().map(|c| c.try_into()).collect::<Vec<_>>())
-            .collect::<Result<Vec<_>>>()?;
+impl Contraption {
+    pub fn new(cells: Grid<Cell>) -> Self {
+        Self { cells }
+    }
 
-        let rows = cells.len() / cols;
+    pub fn get(&self, row: usize, col: usize) -> &Cell {
+        &self.cells[(row, col)]
+    }
 
-        Ok(Grid { cells, rows, cols })
+    pub fn rows(&self) -> usize {
+        self.cells.rows()
     }
 
-    pub fn get<'a>(&'a self, row: usize, col: usize) -> &'a Cell {
-        &self.cells[self.cols * row + col]
+    pub fn cols(&self) -> usize {
+        self.cells.cols()
     }
 
     pub fn starting_photons<'a>(&'a self) -> impl Iterator<Item = Photon> + 'a {
-        (0..self.cols)
+        (0..self.cols())
             .map(|c| Photon {
                 row: 0,
                 col: c,
                 dir: Direction::Down,
             })
-            .chain((0..self.cols).map(|c| Photon {
-                row: self.rows - 1,
+            .chain((0..self.cols()).map(|c| Photon {
+                row: self.rows() - 1,
                 col: c,
                 dir: Direction::Up,
             }))
-            .chain((0..self.rows).map(|r| Photon {
+            .chain((0..self.rows()).map(|r| Photon {
                 row: r,
                 col: 0,
                 dir: Direction::Right,
             }))
-            .chain((0..self.cols).map(|r| Photon {
+            .chain((0..self.rows()).map(|r| Photon {
                 row: r,
-                col: self.cols - 1,
+                col: self.cols() - 1,
                 dir: Direction::Left,
             }))
     }
 
     pub fn num_energized(&self, start: Photon) -> usize {
-        let max_row = self.rows - 1;
-        let max_col = self.cols - 1;
+        self.energize(start, &mut NoRecorder)
+    }
+
+    /// Energized cells in yellow and the photon being traced in red
+    fn frame(&self, energized: &Grid<bool>, photon: &Photon) -> Frame {
+        let mut frame = self.cells.map(|c| Pixel::new(c.glyph(), Rgb::gray(64)));
+        for ((row, col), _) in energized.enumerate().filter(|(_, &e)| e) {
+            frame[(row, col)].color = Rgb::YELLOW;
+        }
+        frame[(photon.row, photon.col)] = Pixel::new('*', Rgb::RED);
 
+        frame
+    }
+
+    /// Count cells energized by firing a photon from start, recording each step it takes
+    pub fn energize(&self, start: Photon, recorder: &mut dyn Recorder) -> usize {
         // keep track of which cells have been energized
-        let mut energized = vec![false; self.cells.len()];
+        let mut energized = Grid::filled(self.rows(), self.cols(), false);
 
         // fire all photons
         let mut photons = vec![start];
@@ -139,11 +134,11 @@ impl Grid {
 
             // while the photon is still valid
             while let Some(mut p) = photon {
-                let seen = energized[self.cols * p.row + p.col];
+                let seen = energized[(p.row, p.col)];
                 match self.get(p.row, p.col) {
                     // always pass through empty cells
                     Cell::Empty => {
-                        photon = p.advance(max_row, max_col);
+                        photon = p.advance(&self.cells);
                     }
                     // always reflect on mirrors
                     Cell::MirrorFwd => {
@@ -153,7 +148,7 @@ impl Grid {
                             Direction::Left => Direction::Down,
                             Direction::Right => Direction::Up,
                         });
-                        photon = p.advance(max_row, max_col);
+                        photon = p.advance(&self.cells);
                     }
                     // always reflect on mirrors
                     Cell::MirrorBack => {
@@ -163,7 +158,7 @@ impl Grid {
                             Direction::Left => Direction::Up,
                             Direction::Right => Direction::Down,
                         });
-                        photon = p.advance(max_row, max_col);
+                        photon = p.advance(&self.cells);
                     }
                     // only split if we haven't already energized this splitter
                     // to avoid infinite loop
@@ -172,7 +167,7 @@ impl Grid {
                             // split into two photons going opposite horizontal directions
                             Direction::Up | Direction::Down => {
                                 if let Some(split) =
-                                    p.with_dir(Direction::Right).advance(max_row, max_col)
+                                    p.with_dir(Direction::Right).advance(&self.cells)
                                 {
                                     photons.push(split);
                                 }
@@ -183,7 +178,7 @@ impl Grid {
                             _ => {}
                         };
 
-                        photon = p.advance(max_row, max_col);
+                        photon = p.advance(&self.cells);
                     }
                     // only split if we haven't already energized this splitter
                     // to avoid infinite loop
@@ -192,7 +187,7 @@ impl Grid {
                             // split into two photons going opposite vertical directions
                             Direction::Left | Direction::Right => {
                                 if let Some(split) =
-                                    p.with_dir(Direction::Down).advance(max_row, max_col)
+                                    p.with_dir(Direction::Down).advance(&self.cells)
                                 {
                                     photons.push(split);
                                 }
@@ -203,7 +198,7 @@ impl Grid {
                             _ => {}
                         };
 
-                        photon = p.advance(max_row, max_col);
+                        photon = p.advance(&self.cells);
                     }
                     _ => {
                         // stop photon to avoid infinite loop
@@ -212,28 +207,59 @@ impl Grid {
                 }
 
                 // always energize after visit
-                energized[self.cols * p.row + p.col] = true;
+                energized[(p.row, p.col)] = true;
+                recorder.draw(|| self.frame(&energized, &p));
             }
         }
 
         // count energized cells
-        energized.into_iter().filter(|x| *x).count()
+        energized.iter().filter(|x| **x).count()
     }
 }
 
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-    let input = File::open(&args[1])?;
-    let reader = io::BufReader::new(input);
+/// Part 1 starts with one photon in top-left moving right
+const START: Photon = Photon {
+    row: 0,
+    col: 0,
+    dir: Direction::Right,
+};
+
+pub struct Day16;
 
-    let grid = Grid::try_from_lines(reader.lines().filter_map(|line| line.ok()))?;
-    let max = grid
-        .starting_photons()
-        .map(|p| grid.num_energized(p))
-        .max()
-        .ok_or(anyhow!("No max"))?;
+impl Solution for Day16 {
+    type Input = Contraption;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("Max Energized Tiles: {}", max);
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Contraption::new(input.parse()?))
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        Ok(grid.num_energized(START))
+    }
 
-    Ok(())
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        grid.starting_photons()
+            .map(|p| grid.num_energized(p))
+            .max()
+            .ok_or(anyhow!("No max"))
+    }
+
+    fn visualize(part: u8, grid: &Self::Input, recorder: &mut dyn Recorder) -> Result<()> {
+        let start = match part {
+            1 => START,
+            // replay the best starting photon
+            2 => grid
+                .starting_photons()
+                .max_by_key(|&p| grid.num_energized(p))
+                .ok_or(anyhow!("No max"))?,
+            _ => return Err(anyhow!("Invalid part: {part}")),
+        };
+
+        grid.energize(start, recorder);
+        Ok(())
+    }
 }
+
+aoc_solution::expected_answers!(Day16);
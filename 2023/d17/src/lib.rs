@@ -0,0 +1,177 @@
+use anyhow::{anyhow, Result};
+use aoc_geometry::Direction;
+use aoc_grid::Grid;
+use aoc_solution::Solution;
+use aoc_visualize::{Frame, NoRecorder, Pixel, Recorder, Rgb};
+use pathfinding::prelude::astar;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Crucible {
+    row: usize,
+    col: usize,
+    dir: Direction,
+    speed: u8,
+}
+
+impl Crucible {
+    pub fn distance(&self, row: usize, col: usize) -> usize {
+        self.row.abs_diff(row) + self.col.abs_diff(col)
+    }
+
+    pub fn advance<T>(self, grid: &Grid<T>, max_speed: u8) -> Option<Self> {
+        if self.speed == max_speed {
+            None
+        } else {
+            let (dc, dr) = self.dir.delta();
+            grid.offset(self.row, self.col, dr, dc)
+                .map(|(row, col)| Self {
+                    row,
+                    col,
+                    speed: self.speed + 1,
+                    ..self
+                })
+        }
+    }
+
+    /// Possible next moves, only allowing turns once the crucible has moved
+    /// at least min_speed blocks in a straight line
+    pub fn successors(
+        &self,
+        map: &Map,
+        min_speed: u8,
+        max_speed: u8,
+    ) -> impl IntoIterator<Item = (Crucible, u32)> {
+        [
+            Some(*self),
+            (self.speed >= min_speed).then(|| Self {
+                dir: self.dir.turn_left(),
+                speed: 0,
+                ..*self
+            }),
+            (self.speed >= min_speed).then(|| Self {
+                dir: self.dir.turn_right(),
+                speed: 0,
+                ..*self
+            }),
+        ]
+        .into_iter()
+        .filter_map(move |c| {
+            c.and_then(|c| c.advance(&map.blocks, max_speed))
+                .map(|c| (c, map.get(c.row, c.col)))
+        })
+        .collect::<Vec<_>>()
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    blocks: Grid<u32>,
+}
+
+impl Map {
+    pub fn new(blocks: Grid<u32>) -> Self {
+        Self { blocks }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> u32 {
+        self.blocks[(row, col)]
+    }
+
+    /// Blocks shaded by heat loss, with the path taken so far in red
+    fn frame(&self, path: &[Crucible]) -> Frame {
+        let mut frame = self.blocks.map(|&heat| {
+            let glyph = char::from_digit(heat, 10).unwrap_or('?');
+            Pixel::new(glyph, Rgb::gray((heat * 28).min(255) as u8))
+        });
+        for c in path {
+            frame[(c.row, c.col)].color = Rgb::RED;
+        }
+
+        frame
+    }
+
+    /// Find the path losing the least heat, then record it one block at a time
+    pub fn min_heat_loss(
+        &self,
+        min_speed: u8,
+        max_speed: u8,
+        recorder: &mut dyn Recorder,
+    ) -> Result<usize> {
+        let goal = (self.blocks.rows() - 1, self.blocks.cols() - 1);
+
+        let starts = &[
+            Crucible {
+                row: 0,
+                col: 0,
+                dir: Direction::Right,
+                speed: 0,
+            },
+            Crucible {
+                row: 0,
+                col: 0,
+                dir: Direction::Down,
+                speed: 0,
+            },
+        ];
+
+        let (path, cost) = starts
+            .iter()
+            .map(|start| {
+                astar(
+                    start,
+                    |c| c.successors(self, min_speed, max_speed),
+                    |c| c.distance(goal.0, goal.1) as u32 / 3,
+                    |c| (c.row, c.col) == goal && c.speed >= min_speed,
+                )
+                .ok_or(anyhow!("Unable to find path"))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .min_by_key(|&(_, c)| c)
+            .ok_or(anyhow!("Unable to find minimum heat loss"))?;
+
+        for i in 1..=path.len() {
+            recorder.draw(|| self.frame(&path[..i]));
+        }
+
+        Ok(cost as usize)
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let digits: Grid<char> = input.parse()?;
+        if let Some((row, col)) = digits.position(|c| !c.is_ascii_digit()) {
+            return Err(anyhow!("Invalid digit at row {row}, col {col}"));
+        }
+
+        Ok(Map::new(digits.map(|c| c.to_digit(10).unwrap())))
+    }
+
+    fn part1(map: &Self::Input) -> Result<usize> {
+        map.min_heat_loss(0, 3, &mut NoRecorder)
+    }
+
+    fn part2(map: &Self::Input) -> Result<usize> {
+        // ultra crucibles
+        map.min_heat_loss(4, 10, &mut NoRecorder)
+    }
+
+    fn visualize(part: u8, map: &Self::Input, recorder: &mut dyn Recorder) -> Result<()> {
+        match part {
+            1 => map.min_heat_loss(0, 3, recorder)?,
+            2 => map.min_heat_loss(4, 10, recorder)?,
+            _ => return Err(anyhow!("Invalid part: {part}")),
+        };
+
+        Ok(())
+    }
+}
+
+aoc_solution::expected_answers!(Day17);
@@ -0,0 +1,149 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Error, Result};
+use aoc_geometry::{boundary_points, interior_points, Direction, Point};
+use aoc_solution::Solution;
+
+/// Parse a direction from either a letter or the last digit of a hex color code
+fn parse_direction(c: char) -> Result<Direction> {
+    match c {
+        '0' => Ok(Direction::Right),
+        '1' => Ok(Direction::Down),
+        '2' => Ok(Direction::Left),
+        '3' => Ok(Direction::Up),
+        c => c.try_into(),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Step {
+    dir: Direction,
+    distance: usize,
+}
+
+impl FromStr for Step {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split(' ');
+
+        let dir = parts
+            .next()
+            .ok_or(anyhow!("Missing direction"))?
+            .chars()
+            .next()
+            .ok_or(anyhow!("Empty direction"))
+            .and_then(parse_direction)
+            .context("Invalid direction")?;
+
+        let distance = parts
+            .next()
+            .ok_or(anyhow!("Missing distance"))?
+            .parse()
+            .context("Invalid distance")?;
+
+        Ok(Step { dir, distance })
+    }
+}
+
+impl Step {
+    /// Decode the "real" step hidden in a hex color code like `(#70c710)`
+    pub fn from_color(s: &str) -> Result<Self> {
+        let start = s.find('#').ok_or(anyhow!("Missing #"))? + 1;
+        let code = &s[start..s.len() - 1];
+
+        let distance = usize::from_str_radix(&code[0..5], 16).context("Invalid distance")?;
+        let dir = code
+            .chars()
+            .last()
+            .ok_or(anyhow!("Empty color code"))
+            .and_then(parse_direction)?;
+
+        Ok(Step { dir, distance })
+    }
+}
+
+#[derive(Debug)]
+struct Trench {
+    vertices: Vec<Point>,
+}
+
+impl Trench {
+    pub fn new() -> Self {
+        Self {
+            vertices: vec![Point::origin()],
+        }
+    }
+
+    pub fn dig(&mut self, step: &Step) {
+        let curr = *self.vertices.last().expect("vertices is never empty");
+        self.vertices
+            .push(curr.step_n(step.dir, step.distance as isize));
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.vertices[0] == *self.vertices.last().expect("vertices is never empty")
+    }
+
+    pub fn volume(&self) -> usize {
+        // the trench itself plus everything it encloses
+        let volume = boundary_points(&self.vertices) + interior_points(&self.vertices);
+        volume as usize
+    }
+}
+
+/// A line of the dig plan, which can be read as either of two steps
+#[derive(Debug)]
+pub struct Instruction {
+    step: Step,
+    color_step: Step,
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let step = s.parse()?;
+        let color_step = Step::from_color(s)?;
+
+        Ok(Instruction { step, color_step })
+    }
+}
+
+fn dig_volume<'a, I: IntoIterator<Item = &'a Step>>(steps: I) -> Result<usize> {
+    let mut trench = Trench::new();
+    for step in steps {
+        trench.dig(step);
+    }
+
+    if !trench.is_closed() {
+        return Err(anyhow!("Trench is not closed"));
+    }
+
+    Ok(trench.volume())
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        input
+            .lines()
+            .map(|line| line.parse().context("Invalid step"))
+            .collect()
+    }
+
+    fn part1(plan: &Self::Input) -> Result<usize> {
+        dig_volume(plan.iter().map(|i| &i.step))
+    }
+
+    fn part2(plan: &Self::Input) -> Result<usize> {
+        dig_volume(plan.iter().map(|i| &i.color_step))
+    }
+}
+
+aoc_solution::expected_answers!(Day18);
@@ -0,0 +1,341 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::iter;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Error, Result};
+use aoc_interval::Interval;
+use aoc_solution::Solution;
+
+mod tree;
+
+pub use tree::{DecisionTree, Node, NodeId};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Op {
+    GreaterThan(usize),
+    LessThan(usize),
+}
+
+#[derive(Clone, Debug)]
+pub struct Condition {
+    category: char,
+    op: Op,
+}
+
+impl Condition {
+    pub fn eval(&self, part: &Part) -> bool {
+        match self.op {
+            Op::GreaterThan(x) => part.ratings[&self.category] > x,
+            Op::LessThan(x) => part.ratings[&self.category] < x,
+        }
+    }
+
+    /// Split parts into those that match and those that don't, leaving out
+    /// either if there are none
+    fn split(&self, part: &PartRange) -> (Option<PartRange>, Option<PartRange>) {
+        let rating = &part.ratings[&self.category];
+        let (matching, unmatched) = match self.op {
+            Op::GreaterThan(x) => {
+                let (below, above) = rating.split_at(x + 1);
+                (above, below)
+            }
+            Op::LessThan(x) => rating.split_at(x),
+        };
+
+        let with = |r: Interval<usize>| (!r.is_empty()).then(|| part.with_rating(self.category, r));
+        (with(matching), with(unmatched))
+    }
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.op {
+            Op::GreaterThan(x) => write!(f, "{}>{}", self.category, x),
+            Op::LessThan(x) => write!(f, "{}<{}", self.category, x),
+        }
+    }
+}
+
+impl FromStr for Condition {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (mut parts, op) = if s.contains('>') {
+            (s.split('>'), Op::GreaterThan(0))
+        } else {
+            (s.split('<'), Op::LessThan(0))
+        };
+
+        let category = parts
+            .next()
+            .ok_or(anyhow!("Missing category"))?
+            .chars()
+            .next()
+            .ok_or(anyhow!("Empty category"))?;
+
+        let value = parts
+            .next()
+            .ok_or(anyhow!("Missing value"))?
+            .parse()
+            .context("Invalid value")?;
+
+        let op = match op {
+            Op::GreaterThan(_) => Op::GreaterThan(value),
+            Op::LessThan(_) => Op::LessThan(value),
+        };
+
+        Ok(Self { category, op })
+    }
+}
+
+#[derive(Debug)]
+pub enum Next {
+    Accepted,
+    Rejected,
+    Workflow(String),
+}
+
+impl FromStr for Next {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "A" => Next::Accepted,
+            "R" => Next::Rejected,
+            w => Next::Workflow(w.into()),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    condition: Option<Condition>,
+    next: Next,
+}
+
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        if s.contains(':') {
+            let mut parts = s.split(':');
+            let condition = Some(
+                parts
+                    .next()
+                    .ok_or(anyhow!("Missing condition"))?
+                    .parse()
+                    .context("Invalid condition")?,
+            );
+
+            let next = parts
+                .next()
+                .ok_or(anyhow!("Missing result"))?
+                .parse()
+                .context("Invalid rule result")?;
+
+            Ok(Self { condition, next })
+        } else {
+            let next = s.parse().context("Invalid rule result")?;
+            Ok(Self {
+                condition: None,
+                next,
+            })
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl FromStr for Workflow {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut parts = s.split(&[',', '{', '}']);
+
+        let name = parts.next().ok_or(anyhow!("Missing workflow name"))?.into();
+        let rules = parts
+            .filter(|&p| !p.is_empty())
+            .map(|p| p.parse().context("Invalid rule"))
+            .collect::<Result<_>>()?;
+
+        Ok(Self { name, rules })
+    }
+}
+
+#[derive(Debug)]
+pub struct Workflows {
+    workflows: HashMap<String, Workflow>,
+}
+
+impl Workflows {
+    pub fn try_from_lines<I: IntoIterator<Item = String>>(lines: I) -> Result<Self> {
+        let workflows = lines
+            .into_iter()
+            .map(|line| {
+                line.parse()
+                    .context("Invalid workflow")
+                    .map(|w: Workflow| (w.name.clone(), w))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { workflows })
+    }
+
+    /// Workflows no part can reach from `in`, in name order
+    pub fn unreachable(&self) -> Vec<&str> {
+        let mut seen = HashSet::from(["in"]);
+        let mut queue = vec!["in"];
+        while let Some(name) = queue.pop() {
+            let Some(workflow) = self.workflows.get(name) else {
+                continue;
+            };
+
+            // rules after one without a condition never run
+            let end = workflow
+                .rules
+                .iter()
+                .position(|r| r.condition.is_none())
+                .map_or(workflow.rules.len(), |i| i + 1);
+            for rule in &workflow.rules[..end] {
+                if let Next::Workflow(w) = &rule.next {
+                    if seen.insert(w) {
+                        queue.push(w);
+                    }
+                }
+            }
+        }
+
+        let mut unreachable = self
+            .workflows
+            .keys()
+            .map(String::as_str)
+            .filter(|w| !seen.contains(w))
+            .collect::<Vec<_>>();
+        unreachable.sort_unstable();
+
+        unreachable
+    }
+}
+
+#[derive(Debug)]
+pub struct Part {
+    ratings: HashMap<char, usize>,
+}
+
+impl Part {
+    pub fn total_rating(&self) -> usize {
+        self.ratings.values().sum()
+    }
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let ratings = s
+            .split(&[',', '{', '}'])
+            .filter(|&r| !r.is_empty())
+            .map(|r| {
+                let mut parts = r.split('=');
+                let category = parts
+                    .next()
+                    .ok_or(anyhow!("Missing category"))?
+                    .chars()
+                    .next()
+                    .ok_or(anyhow!("Empty category"))?;
+                let rating = parts
+                    .next()
+                    .ok_or(anyhow!("Missing rating"))?
+                    .parse()
+                    .context("Invalid rating")?;
+                Ok((category, rating))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self { ratings })
+    }
+}
+
+/// Every part with ratings in the given ranges
+#[derive(Clone, Debug)]
+struct PartRange {
+    ratings: HashMap<char, Interval<usize>>,
+}
+
+impl PartRange {
+    const MIN_RATING: usize = 1;
+    const MAX_RATING: usize = 4000;
+
+    pub fn new() -> Self {
+        Self {
+            ratings: HashMap::from_iter("xmas".chars().zip(iter::repeat(Interval::inclusive(
+                Self::MIN_RATING,
+                Self::MAX_RATING,
+            )))),
+        }
+    }
+
+    fn with_rating(&self, category: char, rating: Interval<usize>) -> Self {
+        Self {
+            ratings: self
+                .ratings
+                .iter()
+                .map(|(c, r)| (*c, if *c == category { rating } else { *r }))
+                .collect(),
+        }
+    }
+
+    pub fn combinations(&self) -> usize {
+        self.ratings.values().map(|r| r.len()).product()
+    }
+}
+
+/// Workflows and the parts to sort through them
+#[derive(Debug)]
+pub struct System {
+    tree: DecisionTree,
+    parts: Vec<Part>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = input.lines().map(String::from);
+        let workflows =
+            Workflows::try_from_lines(lines.by_ref().take_while(|line| !line.is_empty()))?;
+
+        let parts = lines
+            .map(|line| line.parse().context("Invalid Part"))
+            .collect::<Result<_>>()?;
+
+        Ok(System {
+            tree: DecisionTree::compile(&workflows)?,
+            parts,
+        })
+    }
+
+    fn part1(system: &Self::Input) -> Result<usize> {
+        Ok(system
+            .parts
+            .iter()
+            .filter(|&p| system.tree.evaluate(p))
+            .map(|p| p.total_rating())
+            .sum())
+    }
+
+    fn part2(system: &Self::Input) -> Result<usize> {
+        Ok(system.tree.accepted_combinations())
+    }
+}
+
+aoc_solution::expected_answers!(Day19);
@@ -0,0 +1,297 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use anyhow::{anyhow, Result};
+
+use crate::{Condition, Next, Part, PartRange, Workflows};
+
+/// Index of a node in a DecisionTree
+pub type NodeId = usize;
+
+#[derive(Debug)]
+pub enum Node {
+    Accept,
+    Reject,
+    /// A conditional rule of a workflow, and where parts go if it does or
+    /// doesn't match
+    Test {
+        workflow: String,
+        condition: Condition,
+        matched: NodeId,
+        unmatched: NodeId,
+    },
+}
+
+/// Workflows compiled into a graph of conditions, where the rules of each
+/// workflow are chained together and jumping to another workflow is just an
+/// edge to its first rule. Workflows reached from several places are shared
+/// rather than copied.
+#[derive(Debug)]
+pub struct DecisionTree {
+    nodes: Vec<Node>,
+    root: NodeId,
+}
+
+/// How far compiling a workflow has got
+enum State {
+    InProgress,
+    Done(NodeId),
+}
+
+impl DecisionTree {
+    pub const ACCEPT: NodeId = 0;
+    pub const REJECT: NodeId = 1;
+
+    /// Compile the workflows starting at `in`. Fails if a workflow is
+    /// missing, can fall through its last rule, or can lead back to itself.
+    pub fn compile(workflows: &Workflows) -> Result<Self> {
+        if !workflows.workflows.contains_key("in") {
+            return Err(anyhow!("No `in` workflow"));
+        }
+
+        let mut tree = Self {
+            nodes: vec![Node::Accept, Node::Reject],
+            root: Self::REJECT,
+        };
+        let mut states = HashMap::new();
+        let mut path = Vec::new();
+        tree.root = tree.compile_workflow(workflows, "in", &mut states, &mut path)?;
+
+        Ok(tree)
+    }
+
+    fn compile_workflow<'a>(
+        &mut self,
+        workflows: &'a Workflows,
+        name: &'a str,
+        states: &mut HashMap<&'a str, State>,
+        path: &mut Vec<&'a str>,
+    ) -> Result<NodeId> {
+        path.push(name);
+        match states.get(name) {
+            Some(State::Done(id)) => {
+                path.pop();
+                return Ok(*id);
+            }
+            Some(State::InProgress) => {
+                let start = path.iter().position(|&w| w == name).unwrap_or(0);
+                return Err(anyhow!("Workflows loop: {}", path[start..].join(" -> ")));
+            }
+            None => states.insert(name, State::InProgress),
+        };
+
+        let workflow = workflows.workflows.get(name).ok_or_else(|| {
+            anyhow!(
+                "Missing workflow {name}, reached by {}",
+                path[..path.len() - 1].join(" -> ")
+            )
+        })?;
+
+        // chain rules together from the last, which must always match
+        let fallback = workflow
+            .rules
+            .iter()
+            .position(|r| r.condition.is_none())
+            .ok_or_else(|| anyhow!("Parts can fall through workflow {name}"))?;
+
+        let mut id = None;
+        for rule in workflow.rules[..=fallback].iter().rev() {
+            let next = match &rule.next {
+                Next::Accepted => Self::ACCEPT,
+                Next::Rejected => Self::REJECT,
+                Next::Workflow(w) => self.compile_workflow(workflows, w, states, path)?,
+            };
+
+            id = Some(match (&rule.condition, id) {
+                (Some(condition), Some(unmatched)) => {
+                    self.nodes.push(Node::Test {
+                        workflow: name.into(),
+                        condition: condition.clone(),
+                        matched: next,
+                        unmatched,
+                    });
+                    self.nodes.len() - 1
+                }
+                _ => next,
+            });
+        }
+
+        let id = id.expect("Workflow should have a fallback rule");
+        states.insert(name, State::Done(id));
+        path.pop();
+
+        Ok(id)
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn root(&self) -> NodeId {
+        self.root
+    }
+
+    /// Whether the part ends up accepted
+    pub fn evaluate(&self, part: &Part) -> bool {
+        let mut id = self.root;
+        loop {
+            match &self.nodes[id] {
+                Node::Accept => return true,
+                Node::Reject => return false,
+                Node::Test {
+                    condition,
+                    matched,
+                    unmatched,
+                    ..
+                } => {
+                    id = if condition.eval(part) {
+                        *matched
+                    } else {
+                        *unmatched
+                    };
+                }
+            }
+        }
+    }
+
+    /// How many distinct parts with ratings from 1 to 4000 are accepted
+    pub fn accepted_combinations(&self) -> usize {
+        let mut accepted = 0;
+        let mut queue = vec![(self.root, PartRange::new())];
+        while let Some((id, part)) = queue.pop() {
+            match &self.nodes[id] {
+                Node::Accept => accepted += part.combinations(),
+                Node::Reject => {}
+                Node::Test {
+                    condition,
+                    matched,
+                    unmatched,
+                    ..
+                } => {
+                    let (yes, no) = condition.split(&part);
+                    queue.extend(yes.map(|p| (*matched, p)));
+                    queue.extend(no.map(|p| (*unmatched, p)));
+                }
+            }
+        }
+
+        accepted
+    }
+
+    /// Graphviz DOT source drawing the tree, with each condition labelled by
+    /// its workflow
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph workflows {\n");
+        for (id, node) in self.nodes.iter().enumerate() {
+            match node {
+                Node::Accept => writeln!(dot, "  n{id} [label=\"A\", shape=box];"),
+                Node::Reject => writeln!(dot, "  n{id} [label=\"R\", shape=box];"),
+                Node::Test {
+                    workflow,
+                    condition,
+                    matched,
+                    unmatched,
+                } => writeln!(dot, "  n{id} [label=\"{workflow}: {condition}\"];")
+                    .and_then(|_| writeln!(dot, "  n{id} -> n{matched} [label=\"yes\"];"))
+                    .and_then(|_| writeln!(dot, "  n{id} -> n{unmatched} [label=\"no\"];")),
+            }
+            .unwrap();
+        }
+        writeln!(
+            dot,
+            "  start [shape=point];\n  start -> n{};\n}}",
+            self.root
+        )
+        .unwrap();
+
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    fn workflows(input: &str) -> Workflows {
+        Workflows::try_from_lines(
+            input
+                .lines()
+                .take_while(|l| !l.is_empty())
+                .map(String::from),
+        )
+        .unwrap()
+    }
+
+    fn compile_err(input: &str) -> String {
+        DecisionTree::compile(&workflows(input))
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn evaluate() {
+        let tree = DecisionTree::compile(&workflows(EXAMPLE)).unwrap();
+        let accepted = EXAMPLE
+            .lines()
+            .skip_while(|l| !l.is_empty())
+            .skip(1)
+            .map(|l| tree.evaluate(&l.parse().unwrap()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(accepted, [true, false, true, false, true]);
+        assert_eq!(tree.accepted_combinations(), 167409079868000);
+    }
+
+    #[test]
+    fn dot() {
+        let tree = DecisionTree::compile(&workflows("in{x>10:a,R}\na{m<5:R,s>7:A,A}")).unwrap();
+
+        assert_eq!(
+            tree.to_dot(),
+            "digraph workflows {
+  n0 [label=\"A\", shape=box];
+  n1 [label=\"R\", shape=box];
+  n2 [label=\"a: s>7\"];
+  n2 -> n0 [label=\"yes\"];
+  n2 -> n0 [label=\"no\"];
+  n3 [label=\"a: m<5\"];
+  n3 -> n1 [label=\"yes\"];
+  n3 -> n2 [label=\"no\"];
+  n4 [label=\"in: x>10\"];
+  n4 -> n3 [label=\"yes\"];
+  n4 -> n1 [label=\"no\"];
+  start [shape=point];
+  start -> n4;
+}
+"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            compile_err("in{x>10:a,R}\na{m<5:b,A}\nb{in}"),
+            "Workflows loop: in -> a -> b -> in"
+        );
+        assert_eq!(
+            compile_err("in{x>10:a,R}\na{m<5:b,A}"),
+            "Missing workflow b, reached by in -> a"
+        );
+        assert_eq!(
+            compile_err("in{x>10:A}"),
+            "Parts can fall through workflow in"
+        );
+        assert_eq!(compile_err("a{x>10:A,R}"), "No `in` workflow");
+    }
+
+    #[test]
+    fn unreachable() {
+        assert!(workflows(EXAMPLE).unreachable().is_empty());
+        assert_eq!(
+            workflows("in{x>10:a,R,c}\na{A}\nb{a}\nc{A}").unreachable(),
+            ["b", "c"]
+        );
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use aoc_solution::Solution;
+
+use crate::solutions::Visitor;
+
+/// How long to spend measuring each stage of a solution
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// Run repeatedly for this long before measuring, to warm caches and estimate run time
+    pub warm_up: Duration,
+    /// Total time to spend taking samples
+    pub measurement: Duration,
+    pub min_samples: usize,
+    pub max_samples: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(500),
+            measurement: Duration::from_secs(2),
+            min_samples: 5,
+            max_samples: 100,
+        }
+    }
+}
+
+/// Summary statistics of the time per run over all samples, in seconds
+#[derive(Clone, Debug)]
+pub struct Stats {
+    /// sorted ascending
+    samples: Vec<f64>,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<f64>) -> Self {
+        assert!(!samples.is_empty(), "Stats need at least one sample");
+        samples.sort_by(f64::total_cmp);
+        Self { samples }
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.samples.iter().sum::<f64>() / self.len() as f64
+    }
+
+    pub fn median(&self) -> f64 {
+        self.percentile(0.5)
+    }
+
+    /// Sample standard deviation
+    pub fn std_dev(&self) -> f64 {
+        if self.len() < 2 {
+            return 0.0;
+        }
+
+        let mean = self.mean();
+        let var =
+            self.samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (self.len() - 1) as f64;
+        var.sqrt()
+    }
+
+    pub fn min(&self) -> f64 {
+        self.samples[0]
+    }
+
+    pub fn max(&self) -> f64 {
+        self.samples[self.len() - 1]
+    }
+
+    /// Linearly interpolated percentile, p in [0, 1]
+    fn percentile(&self, p: f64) -> f64 {
+        let rank = p * (self.len() - 1) as f64;
+        let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+        self.samples[lo] + (self.samples[hi] - self.samples[lo]) * (rank - lo as f64)
+    }
+
+    /// Number of samples outside Tukey's fences, 1.5 IQR beyond the quartiles
+    pub fn outliers(&self) -> usize {
+        let (q1, q3) = (self.percentile(0.25), self.percentile(0.75));
+        let iqr = q3 - q1;
+        let (lo, hi) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+        self.samples.iter().filter(|&&s| s < lo || s > hi).count()
+    }
+}
+
+/// Time repeated runs of f. Fast functions are run several times per sample so
+/// timer resolution doesn't matter, and slow ones get fewer samples so the
+/// measurement doesn't take forever.
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> Result<T>) -> Result<Stats> {
+    let mut warm_up_runs = 0u32;
+    let start = Instant::now();
+    while warm_up_runs == 0 || start.elapsed() < config.warm_up {
+        black_box(f()?);
+        warm_up_runs += 1;
+    }
+    let estimate = start.elapsed().as_secs_f64() / warm_up_runs as f64;
+
+    let measurement = config.measurement.as_secs_f64();
+    let runs_per_sample =
+        ((measurement / config.max_samples as f64 / estimate).floor() as u32).max(1);
+    let num_samples = ((measurement / (estimate * runs_per_sample as f64)) as usize)
+        .clamp(config.min_samples, config.max_samples);
+
+    let mut samples = Vec::with_capacity(num_samples);
+    for _ in 0..num_samples {
+        let start = Instant::now();
+        for _ in 0..runs_per_sample {
+            black_box(f()?);
+        }
+        samples.push(start.elapsed().as_secs_f64() / runs_per_sample as f64);
+    }
+
+    Ok(Stats::new(samples))
+}
+
+/// Format seconds with 3 significant digits in a readable unit
+pub fn format_duration(secs: f64) -> String {
+    let (value, unit) = match secs {
+        s if s < 1e-6 => (s * 1e9, "ns"),
+        s if s < 1e-3 => (s * 1e6, "µs"),
+        s if s < 1.0 => (s * 1e3, "ms"),
+        s => (s, "s"),
+    };
+
+    let decimals = match value {
+        v if v < 10.0 => 2,
+        v if v < 100.0 => 1,
+        _ => 0,
+    };
+
+    format!("{value:.decimals$} {unit}")
+}
+
+/// Timings of each stage of one day's solution
+pub struct Timings {
+    pub parse: Result<Stats>,
+    pub part1: Result<Stats>,
+    pub part2: Result<Stats>,
+}
+
+/// Benchmark a solution on one input
+pub struct Bench<'a> {
+    pub config: &'a Config,
+    pub input: &'a str,
+}
+
+impl Visitor for Bench<'_> {
+    type Output = Timings;
+
+    fn visit<S: Solution>(self) -> Timings {
+        let parse = measure(self.config, || S::parse(self.input));
+
+        // parts run on a single parsed input
+        match S::parse(self.input) {
+            Ok(input) => Timings {
+                parse,
+                part1: measure(self.config, || S::part1(&input)),
+                part2: measure(self.config, || S::part2(&input)),
+            },
+            Err(e) => Timings {
+                part1: Err(anyhow::anyhow!("Couldn't parse input: {e}")),
+                part2: Err(anyhow::anyhow!("Couldn't parse input: {e}")),
+                parse,
+            },
+        }
+    }
+}
+
+/// Criterion-style one line summary of a stage
+pub fn summary(stage: &Result<Stats>) -> String {
+    match stage {
+        Ok(stats) => format!(
+            "[{} {} {}] {} ± {} ({} samples, {} outliers)",
+            format_duration(stats.min()),
+            format_duration(stats.median()),
+            format_duration(stats.max()),
+            format_duration(stats.mean()),
+            format_duration(stats.std_dev()),
+            stats.len(),
+            stats.outliers()
+        ),
+        Err(e) => format!("failed: {e:#}"),
+    }
+}
+
+fn cell(stage: &Result<Stats>) -> String {
+    match stage {
+        Ok(stats) => format!(
+            "{} ± {}",
+            format_duration(stats.mean()),
+            format_duration(stats.std_dev())
+        ),
+        Err(_) => "failed".to_string(),
+    }
+}
+
+/// Markdown table of timings for each day of a year
+pub fn table(year: u16, config: &Config, days: &[(u8, Timings)]) -> String {
+    let mut table = format!("# {year} benchmarks\n\n");
+    table.push_str("| Day | Parse | Part 1 | Part 2 |\n");
+    table.push_str("| --: | --: | --: | --: |\n");
+    for (day, t) in days {
+        writeln!(
+            table,
+            "| {day} | {} | {} | {} |",
+            cell(&t.parse),
+            cell(&t.part1),
+            cell(&t.part2)
+        )
+        .unwrap();
+    }
+
+    writeln!(
+        table,
+        "\nMean ± standard deviation per run over {}-{} samples taken in {:?} after a {:?} warm-up.",
+        config.min_samples, config.max_samples, config.measurement, config.warm_up
+    )
+    .unwrap();
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new(vec![5.0, 1.0, 3.0, 2.0, 4.0, 100.0]);
+        assert_eq!(stats.len(), 6);
+        assert_eq!(stats.mean(), 115.0 / 6.0);
+        assert_eq!(stats.median(), 3.5);
+        assert_eq!((stats.min(), stats.max()), (1.0, 100.0));
+        assert_eq!(stats.outliers(), 1);
+        assert!(
+            (Stats::new(vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]).std_dev() - 2.138).abs()
+                < 1e-3
+        );
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(0.000_000_123_4), "123 ns");
+        assert_eq!(format_duration(0.000_012_34), "12.3 µs");
+        assert_eq!(format_duration(0.001_234), "1.23 ms");
+        assert_eq!(format_duration(12.34), "12.3 s");
+    }
+
+    #[test]
+    fn measures() {
+        let config = Config {
+            warm_up: Duration::from_millis(1),
+            measurement: Duration::from_millis(5),
+            min_samples: 3,
+            max_samples: 10,
+        };
+
+        let stats = measure(&config, || Ok(black_box(1) + 1)).unwrap();
+        assert!((3..=10).contains(&stats.len()));
+        assert!(measure(&config, || Err::<(), _>(anyhow::anyhow!("oops"))).is_err());
+    }
+
+    #[test]
+    fn markdown() {
+        let config = Config::default();
+        let timings = Timings {
+            parse: Ok(Stats::new(vec![0.001, 0.001])),
+            part1: Ok(Stats::new(vec![0.000_002, 0.000_004])),
+            part2: Err(anyhow::anyhow!("oops")),
+        };
+
+        let table = table(2023, &config, &[(5, timings)]);
+        assert!(table.contains("| 5 | 1.00 ms ± 0.00 ns | 3.00 µs ± 1.41 µs | failed |"));
+    }
+}
@@ -0,0 +1,143 @@
+use std::env;
+
+use anyhow::{anyhow, Result};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies this tool to the Advent of Code servers, as their automation guidelines ask
+const USER_AGENT: &str = "github.com/cirla/advent-of-code";
+
+/// Talks to the Advent of Code website, or anything pretending to be it
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Configure from `AOC_BASE_URL` (defaults to the real site) and
+    /// `AOC_SESSION` (the value of the `session` cookie after logging in)
+    pub fn from_env() -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or(DEFAULT_BASE_URL.to_string());
+        let session = env::var("AOC_SESSION").ok().filter(|s| !s.is_empty());
+
+        Self::new(&base_url, session)
+    }
+
+    fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or(anyhow!(
+            "Set AOC_SESSION to your adventofcode.com session cookie"
+        ))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// GET a page as the logged in user
+    pub fn get(&self, path: &str) -> Result<String> {
+        let request = self
+            .agent
+            .get(&self.url(path))
+            .set("Cookie", &format!("session={}", self.session()?));
+
+        Self::read(path, request.call())
+    }
+
+    /// POST a form as the logged in user
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let request = self
+            .agent
+            .post(&self.url(path))
+            .set("Cookie", &format!("session={}", self.session()?));
+
+        Self::read(path, request.send_form(form))
+    }
+
+    fn read(path: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String> {
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                Err(anyhow!("{path} returned {code}: {}", body.trim()))
+            }
+            Err(e) => Err(anyhow!("Couldn't reach {path}: {e}")),
+        }
+    }
+}
+
+/// Local stand-in for the Advent of Code website
+#[cfg(test)]
+pub mod mock {
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    use tiny_http::{Response, Server};
+
+    /// What the mock server saw of a request
+    #[derive(Debug)]
+    pub struct Request {
+        pub method: String,
+        pub url: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    pub struct MockServer {
+        pub base_url: String,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockServer {
+        /// Serve the given (status, body) responses in order, then 500 for anything else
+        pub fn start(responses: &[(u16, &str)]) -> Self {
+            let server = Server::http("127.0.0.1:0").expect("Couldn't start mock server");
+            let base_url = format!("http://{}", server.server_addr());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let seen = requests.clone();
+            let mut responses = responses
+                .iter()
+                .map(|&(code, body)| (code, body.to_string()))
+                .collect::<Vec<_>>()
+                .into_iter();
+
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let mut body = String::new();
+                    request.as_reader().read_to_string(&mut body).unwrap();
+
+                    seen.lock().unwrap().push(Request {
+                        method: request.method().to_string(),
+                        url: request.url().to_string(),
+                        cookie: request
+                            .headers()
+                            .iter()
+                            .find(|h| h.field.equiv("Cookie"))
+                            .map(|h| h.value.to_string()),
+                        body,
+                    });
+
+                    let (code, body) = responses
+                        .next()
+                        .unwrap_or((500, "Unexpected request".to_string()));
+                    let _ = request.respond(Response::from_string(body).with_status_code(code));
+                }
+            });
+
+            Self { base_url, requests }
+        }
+
+        pub fn requests(&self) -> std::sync::MutexGuard<'_, Vec<Request>> {
+            self.requests.lock().unwrap()
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::client::Client;
+
+/// Where a day's input is cached, e.g. `<root>/2023/d05/input.txt`
+pub fn input_path(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string())
+        .join(format!("d{day:02}"))
+        .join("input.txt")
+}
+
+/// Download a day's input unless it's already cached, returning its path
+pub fn fetch(client: &Client, root: &Path, year: u16, day: u8) -> Result<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(anyhow!("Invalid day: {day}"));
+    }
+
+    let path = input_path(root, year, day);
+    if path.exists() {
+        return Ok(path);
+    }
+
+    let input = client
+        .get(&format!("/{year}/day/{day}/input"))
+        .with_context(|| format!("Couldn't fetch input for {year} day {day}"))?;
+
+    let dir = path.parent().expect("input path always has a parent");
+    fs::create_dir_all(dir).with_context(|| format!("Couldn't create {}", dir.display()))?;
+    fs::write(&path, input).with_context(|| format!("Couldn't write {}", path.display()))?;
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::MockServer;
+
+    /// Fresh empty directory to fetch into
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-fetch-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn fetches_once() {
+        let server = MockServer::start(&[(200, "1\n2\n3\n")]);
+        let client = Client::new(&server.base_url, Some("abc123".into()));
+        let root = temp_root("once");
+
+        let path = fetch(&client, &root, 2023, 5).unwrap();
+        assert_eq!(path, root.join("2023/d05/input.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+
+        // cached, so the server isn't asked again
+        assert_eq!(fetch(&client, &root, 2023, 5).unwrap(), path);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].url, "/2023/day/5/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let server = MockServer::start(&[(400, "Please log in")]);
+        let client = Client::new(&server.base_url, Some("expired".into()));
+        let root = temp_root("errors");
+
+        let err = fetch(&client, &root, 2022, 1).unwrap_err();
+        assert!(format!("{err:#}").contains("400: Please log in"));
+        assert!(!input_path(&root, 2022, 1).exists());
+    }
+
+    #[test]
+    fn requires_session() {
+        let server = MockServer::start(&[]);
+        let client = Client::new(&server.base_url, None);
+
+        assert!(fetch(&client, &temp_root("session"), 2022, 1).is_err());
+        assert!(server.requests().is_empty());
+    }
+}
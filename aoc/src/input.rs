@@ -0,0 +1,113 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_solution::Parameters;
+
+/// Where to read a puzzle input from
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a path
+    pub fn new(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::Path(path.into()),
+        }
+    }
+
+    /// Every input named on the command line. Stdin can only be read once, so
+    /// it may only be named once.
+    pub fn all(args: &[String]) -> Result<Vec<Self>> {
+        if args.is_empty() {
+            return Err(anyhow!("No input given: pass a path, or - for stdin"));
+        }
+
+        let sources = args.iter().map(|arg| Self::new(arg)).collect::<Vec<_>>();
+        if sources.iter().filter(|&s| s == &Self::Stdin).count() > 1 {
+            return Err(anyhow!("Stdin (-) can only be given as an input once"));
+        }
+
+        Ok(sources)
+    }
+
+    /// Parameters for the input, from the parameters file next to it
+    pub fn parameters(&self) -> Result<Parameters> {
+        match self {
+            Self::Stdin => Ok(Parameters::default()),
+            Self::Path(path) => Parameters::for_input(path),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        self.read_from(io::stdin().lock())
+    }
+
+    /// Read the input, using `stdin` in place of the real one
+    fn read_from(&self, mut stdin: impl Read) -> Result<String> {
+        match self {
+            Self::Stdin => {
+                let mut input = String::new();
+                stdin
+                    .read_to_string(&mut input)
+                    .context("Couldn't read stdin")?;
+                Ok(input)
+            }
+            Self::Path(path) => fs::read_to_string(path)
+                .with_context(|| format!("Couldn't read {}", path.display())),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!(
+            Source::all(&args(&["example.txt", "-", "input.txt"])).unwrap(),
+            vec![
+                Source::Path("example.txt".into()),
+                Source::Stdin,
+                Source::Path("input.txt".into()),
+            ]
+        );
+        assert!(Source::all(&[]).is_err());
+        assert!(Source::all(&args(&["-", "-"])).is_err());
+    }
+
+    #[test]
+    fn reads() {
+        let stdin = "1\n2\n3\n".as_bytes();
+        assert_eq!(Source::Stdin.read_from(stdin).unwrap(), "1\n2\n3\n");
+
+        let path = std::env::temp_dir().join(format!("aoc-input-{}.txt", std::process::id()));
+        fs::write(&path, "from a file\n").unwrap();
+        let source = Source::Path(path.clone());
+        assert_eq!(source.read_from(io::empty()).unwrap(), "from a file\n");
+        fs::remove_file(&path).unwrap();
+
+        let err = source.read_from(io::empty()).unwrap_err();
+        assert!(err.to_string().starts_with("Couldn't read "));
+    }
+}
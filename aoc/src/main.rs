@@ -0,0 +1,256 @@
+use std::env;
+use std::fs::{self, read_to_string};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_solution::Parameters;
+use aoc_visualize::Recording;
+
+mod bench;
+mod client;
+mod fetch;
+mod input;
+mod solutions;
+mod submit;
+
+const USAGE: &str = "Usage:
+  aoc run <year> <day> <part> <input>...
+  aoc fetch <year> <day>
+  aoc submit <year> <day> <part> [answer]
+  aoc bench <year> [day]
+  aoc visualize <year> <day> <part> <input> [output.gif|output.png|output.ppm]";
+
+/// Local record of submitted answers, relative to the root
+const ANSWERS: &str = "answers.json";
+
+/// Benchmark results for a year, relative to the year's directory
+const BENCH: &str = "BENCH.md";
+
+/// Most frames kept when visualizing; longer simulations are sampled evenly
+const MAX_FRAMES: usize = 500;
+/// Time each frame is shown when visualizing
+const FRAME_DELAY: Duration = Duration::from_millis(50);
+/// Width and height in pixels of a cell in exported images
+const CELL_SIZE: usize = 4;
+
+/// Explain what's wrong with the command line, followed by how to use it
+fn usage(problem: &str) -> anyhow::Error {
+    anyhow!("{problem}\n\n{USAGE}")
+}
+
+fn parse_arg<T: FromStr>(name: &str, value: &str) -> Result<T>
+where
+    T::Err: std::error::Error + Send + Sync + 'static,
+{
+    value
+        .parse()
+        .with_context(|| format!("Invalid {}: {}", name, value))
+}
+
+/// Directory containing the per-year solution directories: `AOC_ROOT` if set,
+/// otherwise the workspace this binary was built from
+fn root() -> PathBuf {
+    env::var_os("AOC_ROOT")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| {
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .parent()
+                .expect("aoc crate is inside the workspace")
+                .to_path_buf()
+        })
+}
+
+/// Solve one part for each input (a path, or `-` for stdin). With several
+/// inputs, each answer is labelled with its input and a failure doesn't stop
+/// the rest from being answered.
+fn run(args: &[String]) -> Result<()> {
+    let [year, day, part, inputs @ ..] = args else {
+        return Err(usage("Expected a year, day and part"));
+    };
+
+    let year = parse_arg("year", year)?;
+    let day = parse_arg("day", day)?;
+    let part = parse_arg("part", part)?;
+    let sources = input::Source::all(inputs).map_err(|e| usage(&e.to_string()))?;
+
+    if let [source] = &sources[..] {
+        let answer = solutions::solve(year, day, part, &source.read()?, &source.parameters()?)?;
+        println!("{answer}");
+        return Ok(());
+    }
+
+    let mut failed = 0;
+    for source in &sources {
+        match source
+            .read()
+            .and_then(|input| solutions::solve(year, day, part, &input, &source.parameters()?))
+        {
+            Ok(answer) => println!("{source}: {answer}"),
+            Err(e) => {
+                eprintln!("{source}: {e:#}");
+                failed += 1;
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        n => Err(anyhow!("{n} of {} inputs failed", sources.len())),
+    }
+}
+
+fn fetch(args: &[String]) -> Result<()> {
+    let [year, day] = args else {
+        return Err(usage("Expected a year and day"));
+    };
+
+    let year = parse_arg("year", year)?;
+    let day = parse_arg("day", day)?;
+    let path = fetch::fetch(&client::Client::from_env(), &root(), year, day)?;
+
+    println!("{}", path.display());
+
+    Ok(())
+}
+
+/// Submit an answer, by default the solution's answer for the cached input
+fn submit(args: &[String]) -> Result<()> {
+    let (year, day, part, answer) = match args {
+        [year, day, part] => (year, day, part, None),
+        [year, day, part, answer] => (year, day, part, Some(answer.clone())),
+        _ => return Err(usage("Expected a year, day, part and optional answer")),
+    };
+
+    let year = parse_arg("year", year)?;
+    let day = parse_arg("day", day)?;
+    let part = parse_arg("part", part)?;
+
+    let root = root();
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let path = fetch::input_path(&root, year, day);
+            let input = read_to_string(&path)
+                .with_context(|| format!("Couldn't read {}", path.display()))?;
+            let answer = solutions::solve(year, day, part, &input, &Parameters::for_input(&path)?)?;
+            println!("{}", answer);
+            answer
+        }
+    };
+
+    let client = client::Client::from_env();
+    let outcome = submit::submit(&client, &root.join(ANSWERS), year, day, part, &answer)?;
+
+    println!("{}", outcome);
+
+    Ok(())
+}
+
+/// Time each stage of every solution for a year (or just one day) on its cached input.
+/// Benchmarking a whole year also writes the results to `<year>/BENCH.md`.
+fn bench(args: &[String]) -> Result<()> {
+    let (year, day) = match args {
+        [year] => (parse_arg::<u16>("year", year)?, None),
+        [year, day] => (parse_arg("year", year)?, Some(parse_arg::<u8>("day", day)?)),
+        _ => return Err(usage("Expected a year and optional day")),
+    };
+
+    let days = solutions::DAYS
+        .iter()
+        .filter(|&&(y, d)| y == year && day.is_none_or(|day| d == day))
+        .map(|&(_, d)| d)
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err(anyhow!("No solutions to benchmark"));
+    }
+
+    let root = root();
+    let config = bench::Config::default();
+    let mut timings = Vec::new();
+    for day in days {
+        let path = fetch::input_path(&root, year, day);
+        let Ok(input) = read_to_string(&path) else {
+            eprintln!(
+                "Skipping {year} day {day}: couldn't read {}",
+                path.display()
+            );
+            continue;
+        };
+
+        let t = solutions::visit(
+            year,
+            day,
+            bench::Bench {
+                config: &config,
+                input: &input,
+            },
+        )?;
+        for (stage, stats) in [
+            ("parse", &t.parse),
+            ("part 1", &t.part1),
+            ("part 2", &t.part2),
+        ] {
+            eprintln!("{year} day {day} {stage}: {}", bench::summary(stats));
+        }
+
+        timings.push((day, t));
+    }
+
+    let table = bench::table(year, &config, &timings);
+    println!("{}", table);
+
+    if day.is_none() {
+        let path = root.join(year.to_string()).join(BENCH);
+        fs::write(&path, table).with_context(|| format!("Couldn't write {}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Record a part's simulation, then animate it in the terminal or export it as images
+fn visualize(args: &[String]) -> Result<()> {
+    let (year, day, part, source, output) = match args {
+        [year, day, part, input] => (year, day, part, input, None),
+        [year, day, part, input, output] => (year, day, part, input, Some(Path::new(output))),
+        _ => {
+            return Err(usage(
+                "Expected a year, day, part, input and optional output",
+            ))
+        }
+    };
+
+    let year = parse_arg("year", year)?;
+    let day = parse_arg("day", day)?;
+    let part = parse_arg("part", part)?;
+    let input = input::Source::new(source).read()?;
+
+    let mut recording = Recording::new(MAX_FRAMES);
+    solutions::visualize(year, day, part, &input, &mut recording)?;
+    eprintln!(
+        "Recorded {} frames, keeping {}",
+        recording.seen(),
+        recording.frames().len()
+    );
+
+    match output {
+        Some(output) => recording.save(output, CELL_SIZE, FRAME_DELAY.as_millis() as u16),
+        None => aoc_visualize::play(&recording.frames(), FRAME_DELAY, &mut io::stdout().lock()),
+    }
+}
+
+fn main() -> Result<()> {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.split_first() {
+        Some((cmd, rest)) if cmd == "run" => run(rest),
+        Some((cmd, rest)) if cmd == "fetch" => fetch(rest),
+        Some((cmd, rest)) if cmd == "submit" => submit(rest),
+        Some((cmd, rest)) if cmd == "bench" => bench(rest),
+        Some((cmd, rest)) if cmd == "visualize" => visualize(rest),
+        Some((cmd, _)) => Err(usage(&format!("Unknown command: {cmd}"))),
+        None => Err(usage("No command given")),
+    }
+}
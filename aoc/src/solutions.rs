@@ -0,0 +1,134 @@
+use anyhow::{anyhow, Result};
+use aoc_solution::{Parameters, Solution};
+use aoc_visualize::Recorder;
+
+/// Something to do with a day's solution, generic over its types
+pub trait Visitor {
+    type Output;
+
+    fn visit<S: Solution>(self) -> Self::Output;
+}
+
+/// Map each (year, day) to the type implementing its solution.
+/// Generates `visit`, which looks up a day and hands its type to a Visitor,
+/// and `DAYS`, the list of every solved day.
+macro_rules! solutions {
+    ($(($year:literal, $day:literal) => $solution:path,)*) => {
+        /// Every (year, day) with a solution
+        pub const DAYS: &[(u16, u8)] = &[$(($year, $day)),*];
+
+        /// Hand the solution for a given day to a visitor
+        pub fn visit<V: Visitor>(year: u16, day: u8, visitor: V) -> Result<V::Output> {
+            match (year, day) {
+                $(($year, $day) => Ok(visitor.visit::<$solution>()),)*
+                _ => Err(anyhow!("No solution for {year} day {day}")),
+            }
+        }
+    };
+}
+
+solutions! {
+    (2021, 1) => aoc_2021_d01::Day01,
+    (2021, 2) => aoc_2021_d02::Day02,
+    (2021, 3) => aoc_2021_d03::Day03,
+    (2021, 4) => aoc_2021_d04::Day04,
+    (2021, 5) => aoc_2021_d05::Day05,
+    (2021, 6) => aoc_2021_d06::Day06,
+    (2021, 7) => aoc_2021_d07::Day07,
+    (2021, 8) => aoc_2021_d08::Day08,
+    (2021, 9) => aoc_2021_d09::Day09,
+    (2022, 1) => aoc_2022_d01::Day01,
+    (2022, 2) => aoc_2022_d02::Day02,
+    (2022, 3) => aoc_2022_d03::Day03,
+    (2022, 4) => aoc_2022_d04::Day04,
+    (2022, 5) => aoc_2022_d05::Day05,
+    (2022, 6) => aoc_2022_d06::Day06,
+    (2022, 7) => aoc_2022_d07::Day07,
+    (2022, 8) => aoc_2022_d08::Day08,
+    (2022, 9) => aoc_2022_d09::Day09,
+    (2022, 10) => aoc_2022_d10::Day10,
+    (2022, 11) => aoc_2022_d11::Day11,
+    (2022, 12) => aoc_2022_d12::Day12,
+    (2022, 13) => aoc_2022_d13::Day13,
+    (2022, 15) => aoc_2022_d15::Day15,
+    (2023, 1) => aoc_2023_d01::Day01,
+    (2023, 2) => aoc_2023_d02::Day02,
+    (2023, 3) => aoc_2023_d03::Day03,
+    (2023, 4) => aoc_2023_d04::Day04,
+    (2023, 5) => aoc_2023_d05::Day05,
+    (2023, 6) => aoc_2023_d06::Day06,
+    (2023, 7) => aoc_2023_d07::Day07,
+    (2023, 8) => aoc_2023_d08::Day08,
+    (2023, 9) => aoc_2023_d09::Day09,
+    (2023, 10) => aoc_2023_d10::Day10,
+    (2023, 11) => aoc_2023_d11::Day11,
+    (2023, 12) => aoc_2023_d12::Day12,
+    (2023, 13) => aoc_2023_d13::Day13,
+    (2023, 14) => aoc_2023_d14::Day14,
+    (2023, 15) => aoc_2023_d15::Day15,
+    (2023, 16) => aoc_2023_d16::Day16,
+    (2023, 17) => aoc_2023_d17::Day17,
+    (2023, 18) => aoc_2023_d18::Day18,
+    (2023, 19) => aoc_2023_d19::Day19,
+}
+
+struct Solve<'a> {
+    part: u8,
+    input: &'a str,
+    parameters: &'a Parameters,
+}
+
+impl Visitor for Solve<'_> {
+    type Output = Result<String>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        S::solve_with(self.part, self.input, self.parameters)
+    }
+}
+
+/// Solve a single part of a puzzle given the contents of its input and any
+/// parameters for it
+pub fn solve(year: u16, day: u8, part: u8, input: &str, parameters: &Parameters) -> Result<String> {
+    visit(
+        year,
+        day,
+        Solve {
+            part,
+            input,
+            parameters,
+        },
+    )?
+}
+
+struct Visualize<'a> {
+    part: u8,
+    input: &'a str,
+    recorder: &'a mut dyn Recorder,
+}
+
+impl Visitor for Visualize<'_> {
+    type Output = Result<()>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        S::visualize(self.part, &S::parse(self.input)?, self.recorder)
+    }
+}
+
+/// Record frames of the simulation behind a single part of a puzzle
+pub fn visualize(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    recorder: &mut dyn Recorder,
+) -> Result<()> {
+    visit(
+        year,
+        day,
+        Visualize {
+            part,
+            input,
+            recorder,
+        },
+    )?
+}
@@ -0,0 +1,286 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+/// Hint given along with a wrong answer
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Guess {
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Hint>,
+}
+
+/// Everything submitted so far for one part of one day
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct History {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correct: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<Guess>,
+}
+
+impl History {
+    /// Explain why an answer shouldn't be submitted, if it's already known to be wrong
+    pub fn known_wrong(&self, answer: &str) -> Option<String> {
+        if let Some(correct) = &self.correct {
+            return (correct != answer).then(|| format!("The correct answer is {correct}"));
+        }
+
+        if self.wrong.iter().any(|g| g.answer == answer) {
+            return Some(format!("{answer} was already rejected"));
+        }
+
+        // numeric answers can be ruled out by earlier hints
+        let value: i128 = answer.parse().ok()?;
+        self.wrong.iter().find_map(|g| {
+            let guess: i128 = g.answer.parse().ok()?;
+            match g.hint? {
+                Hint::TooHigh if value >= guess => Some(format!("{} was too high", g.answer)),
+                Hint::TooLow if value <= guess => Some(format!("{} was too low", g.answer)),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// Local record of submitted answers, stored as JSON keyed by year, day and part
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Answers {
+    years: BTreeMap<u16, BTreeMap<u8, BTreeMap<u8, History>>>,
+}
+
+impl Answers {
+    /// Load the database, which is empty if it doesn't exist yet
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("Couldn't read {}", path.display()))?;
+        serde_json::from_str(&json)
+            .with_context(|| format!("Invalid answers in {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json + "\n").with_context(|| format!("Couldn't write {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&History> {
+        self.years.get(&year)?.get(&day)?.get(&part)
+    }
+
+    fn get_mut(&mut self, year: u16, day: u8, part: u8) -> &mut History {
+        self.years
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .entry(part)
+            .or_default()
+    }
+}
+
+/// What the server said about a submitted answer
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Wrong(Option<Hint>),
+    /// Rate limited; try again later
+    TooSoon(String),
+    /// Either already solved or part 1 isn't solved yet
+    WrongLevel,
+    /// Not submitted because the answer is already known to be right or wrong
+    Known(String),
+}
+
+impl Outcome {
+    /// Interpret the HTML page returned after submitting an answer
+    pub fn parse(page: &str) -> Result<Self> {
+        if page.contains("That's the right answer") {
+            Ok(Self::Correct)
+        } else if page.contains("That's not the right answer") {
+            Ok(Self::Wrong(if page.contains("too high") {
+                Some(Hint::TooHigh)
+            } else if page.contains("too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            }))
+        } else if let Some(start) = page.find("You gave an answer too recently") {
+            // keep just the sentence about how long to wait
+            let wait = page[start..]
+                .find("You have ")
+                .map(|i| &page[start + i..])
+                .and_then(|s| s.split_once('.'))
+                .map(|(s, _)| s.to_string())
+                .unwrap_or_default();
+            Ok(Self::TooSoon(wait))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Ok(Self::WrongLevel)
+        } else {
+            Err(anyhow!("Unrecognized response to answer submission"))
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Correct => write!(f, "Correct!"),
+            Self::Wrong(None) => write!(f, "Wrong"),
+            Self::Wrong(Some(Hint::TooHigh)) => write!(f, "Wrong (too high)"),
+            Self::Wrong(Some(Hint::TooLow)) => write!(f, "Wrong (too low)"),
+            Self::TooSoon(wait) => write!(f, "Answered too recently. {wait}"),
+            Self::WrongLevel => write!(f, "Already solved, or the previous part isn't yet"),
+            Self::Known(why) => write!(f, "Not submitted: {why}"),
+        }
+    }
+}
+
+/// Submit an answer unless the answers database at `db` already knows it's wrong,
+/// then record the result
+pub fn submit(
+    client: &Client,
+    db: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome> {
+    if !(1..=2).contains(&part) {
+        return Err(anyhow!("Invalid part: {part}"));
+    }
+
+    let answer = answer.trim();
+    if answer.is_empty() {
+        return Err(anyhow!("Empty answer"));
+    }
+
+    let mut answers = Answers::load(db)?;
+    if let Some(history) = answers.get(year, day, part) {
+        if history.correct.as_deref() == Some(answer) {
+            return Ok(Outcome::Known(format!("{answer} is already correct")));
+        }
+
+        if let Some(why) = history.known_wrong(answer) {
+            return Ok(Outcome::Known(why));
+        }
+    }
+
+    let page = client.post(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let outcome = Outcome::parse(&page)?;
+
+    let history = answers.get_mut(year, day, part);
+    match &outcome {
+        Outcome::Correct => history.correct = Some(answer.to_string()),
+        &Outcome::Wrong(hint) => history.wrong.push(Guess {
+            answer: answer.to_string(),
+            hint,
+        }),
+        _ => return Ok(outcome),
+    }
+    answers.save(db)?;
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock::MockServer;
+
+    const TOO_HIGH: &str =
+        "<article><p>That's not the right answer; your answer is too high.</p></article>";
+    const WRONG: &str = "<article><p>That's not the right answer.</p></article>";
+    const RIGHT: &str =
+        "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_SOON: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 42s left to wait. <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>";
+
+    /// Path to a fresh answers database
+    fn temp_db(name: &str) -> std::path::PathBuf {
+        let db =
+            std::env::temp_dir().join(format!("aoc-answers-{}-{name}.json", std::process::id()));
+        let _ = fs::remove_file(&db);
+        db
+    }
+
+    #[test]
+    fn parse_outcome() {
+        assert_eq!(
+            Outcome::parse(TOO_HIGH).unwrap(),
+            Outcome::Wrong(Some(Hint::TooHigh))
+        );
+        assert_eq!(Outcome::parse(WRONG).unwrap(), Outcome::Wrong(None));
+        assert_eq!(Outcome::parse(RIGHT).unwrap(), Outcome::Correct);
+        assert_eq!(
+            Outcome::parse(TOO_SOON).unwrap(),
+            Outcome::TooSoon("You have 42s left to wait".into())
+        );
+        assert!(Outcome::parse("<html></html>").is_err());
+    }
+
+    #[test]
+    fn records_guesses() {
+        let server = MockServer::start(&[(200, TOO_HIGH), (200, WRONG), (200, RIGHT)]);
+        let client = Client::new(&server.base_url, Some("abc123".into()));
+        let db = temp_db("guesses");
+
+        let submit = |answer| submit(&client, &db, 2023, 1, 2, answer).unwrap();
+        assert_eq!(submit("500"), Outcome::Wrong(Some(Hint::TooHigh)));
+        assert_eq!(submit("100"), Outcome::Wrong(None));
+        assert_eq!(submit("600"), Outcome::Known("500 was too high".into()));
+        assert_eq!(
+            submit("100"),
+            Outcome::Known("100 was already rejected".into())
+        );
+        assert_eq!(submit("200"), Outcome::Correct);
+        assert_eq!(
+            submit("300"),
+            Outcome::Known("The correct answer is 200".into())
+        );
+
+        // only the three unknown answers were sent
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/2023/day/1/answer");
+        assert_eq!(requests[0].body, "level=2&answer=500");
+
+        let answers = Answers::load(&db).unwrap();
+        let history = answers.get(2023, 1, 2).unwrap();
+        assert_eq!(history.correct.as_deref(), Some("200"));
+        assert_eq!(history.wrong.len(), 2);
+
+        fs::remove_file(db).unwrap();
+    }
+
+    #[test]
+    fn rate_limit_not_recorded() {
+        let server = MockServer::start(&[(200, TOO_SOON)]);
+        let client = Client::new(&server.base_url, Some("abc123".into()));
+        let db = temp_db("rate-limit");
+
+        let outcome = submit(&client, &db, 2022, 3, 1, "7").unwrap();
+        assert!(matches!(outcome, Outcome::TooSoon(_)));
+        assert!(!db.exists());
+    }
+}
@@ -0,0 +1,168 @@
+//! Skip ahead in simulations that eventually repeat themselves.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Where a sequence of states starts repeating
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    /// Steps taken before the first state that repeats
+    pub prefix: usize,
+    /// Steps between repeats
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as step n
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+fn nth<T: Clone>(state: &T, n: usize, step: &mut impl FnMut(&T) -> T) -> T {
+    (0..n).fold(state.clone(), |state, _| step(&state))
+}
+
+/// Find the cycle with Brent's algorithm, and the state after n steps. Only
+/// keeps a couple of states around, but calls step several times for each.
+/// The cycle is None if step n was reached before finding it.
+pub fn detect_cycle<T: Clone + PartialEq>(
+    initial: T,
+    n: usize,
+    mut step: impl FnMut(&T) -> T,
+) -> (Option<Cycle>, T) {
+    if n == 0 {
+        return (None, initial);
+    }
+
+    // the hare runs ahead for doubling distances, with the tortoise waiting
+    // where it started each time, until the hare laps it
+    let (mut power, mut period, mut steps) = (1, 1, 1);
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if steps == n {
+            return (None, hare);
+        }
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+        steps += 1;
+    }
+
+    // with the hare a period ahead, they first meet at the start of the cycle
+    let mut prefix = 0;
+    let mut tortoise = initial.clone();
+    let mut hare = nth(&initial, period, &mut step);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    let cycle = Cycle { prefix, period };
+    let state = match cycle.equivalent(n) {
+        n if n < prefix => nth(&initial, n, &mut step),
+        n => nth(&tortoise, n - prefix, &mut step),
+    };
+
+    (Some(cycle), state)
+}
+
+/// Find the cycle by remembering every state, and the state after n steps.
+/// Calls step once for each state before the first repeat, which is better
+/// when stepping is slow or has side effects. The cycle is None if step n
+/// was reached before finding it.
+pub fn detect_cycle_hashed<T: Clone + Eq + Hash>(
+    initial: T,
+    n: usize,
+    mut step: impl FnMut(&T) -> T,
+) -> (Option<Cycle>, T) {
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+    while !seen.contains_key(&state) {
+        if history.len() == n {
+            return (None, state);
+        }
+        seen.insert(state.clone(), history.len());
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+
+    let prefix = seen[&state];
+    let cycle = Cycle {
+        prefix,
+        period: history.len() - prefix,
+    };
+
+    (Some(cycle), history.swap_remove(cycle.equivalent(n)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 0, 1, ..., 9, then round and round 3..10
+    fn step(x: &usize) -> usize {
+        if *x == 9 {
+            3
+        } else {
+            x + 1
+        }
+    }
+
+    #[test]
+    fn brent() {
+        let (cycle, state) = detect_cycle(0, 1_000_000_000_000, step);
+        assert_eq!(
+            cycle,
+            Some(Cycle {
+                prefix: 3,
+                period: 7
+            })
+        );
+        assert_eq!(state, 3 + (1_000_000_000_000 - 3) % 7);
+
+        assert_eq!(detect_cycle(0, 2, step), (None, 2));
+        assert_eq!(detect_cycle(0, 30, step).1, 9);
+        assert_eq!(
+            detect_cycle(5, 1, |_| 5),
+            (
+                Some(Cycle {
+                    prefix: 0,
+                    period: 1
+                }),
+                5
+            )
+        );
+
+        // never repeats, but only needs to get to step n
+        assert_eq!(detect_cycle(0u64, 1000, |x| x + 1), (None, 1000));
+    }
+
+    #[test]
+    fn hashed() {
+        for n in 0..30 {
+            let steps = std::cell::Cell::new(0);
+            let (cycle, state) = detect_cycle_hashed(0, n, |x| {
+                steps.set(steps.get() + 1);
+                step(x)
+            });
+
+            assert_eq!(state, nth(&0, n, &mut step));
+            assert_eq!(state, detect_cycle(0, n, step).1);
+            assert_eq!(steps.get(), n.min(10));
+            assert_eq!(cycle.is_some(), n >= 10);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+mod direction;
+mod point;
+mod polygon;
+
+pub use direction::{Direction, Turn};
+pub use point::{Coord, Point};
+pub use polygon::{boundary_points, double_area, interior_points};
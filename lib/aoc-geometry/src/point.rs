@@ -0,0 +1,147 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+use num::{Integer, PrimInt};
+
+use crate::Direction;
+
+/// Any primitive integer usable as a point coordinate
+pub trait Coord: PrimInt + Integer + Default + Hash + Debug {}
+
+impl<T: PrimInt + Integer + Default + Hash + Debug> Coord for T {}
+
+/// Distance between two coordinates that can't overflow or go negative
+pub(crate) fn abs_diff<T: Coord>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// Point on the integer plane, with y increasing downwards
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point<T = isize> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Point<T> {
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn origin() -> Self {
+        Default::default()
+    }
+
+    /// https://en.wikipedia.org/wiki/Taxicab_geometry
+    pub fn manhattan(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// https://en.wikipedia.org/wiki/Chebyshev_distance
+    pub fn chebyshev(&self, other: &Self) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// Move n steps in a direction, or None if a coordinate would overflow
+    /// (e.g. moving up or left from 0 with unsigned coordinates)
+    pub fn checked_step_n(self, dir: Direction, n: T) -> Option<Self> {
+        match dir {
+            Direction::Up => self.y.checked_sub(&n).map(|y| Self { y, ..self }),
+            Direction::Down => self.y.checked_add(&n).map(|y| Self { y, ..self }),
+            Direction::Left => self.x.checked_sub(&n).map(|x| Self { x, ..self }),
+            Direction::Right => self.x.checked_add(&n).map(|x| Self { x, ..self }),
+        }
+    }
+
+    pub fn checked_step(self, dir: Direction) -> Option<Self> {
+        self.checked_step_n(dir, T::one())
+    }
+
+    /// Move n steps in a direction. Panics if a coordinate would overflow.
+    pub fn step_n(self, dir: Direction, n: T) -> Self {
+        self.checked_step_n(dir, n)
+            .unwrap_or_else(|| panic!("{self:?} stepped {n:?} {dir:?} out of range"))
+    }
+
+    pub fn step(self, dir: Direction) -> Self {
+        self.step_n(dir, T::one())
+    }
+}
+
+impl<T: Coord> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// Parse a point from `x,y`
+impl<T> FromStr for Point<T>
+where
+    T: Coord + FromStr,
+    T::Err: Display,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y) = s.split_once(',').ok_or(anyhow!("Invalid point: {}", s))?;
+
+        let x = x.trim().parse().map_err(|e| anyhow!("Invalid x: {e}"))?;
+        let y = y.trim().parse().map_err(|e| anyhow!("Invalid y: {e}"))?;
+
+        Ok(Self::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let a: Point = "1,-2".parse().unwrap();
+        let b = Point::new(-3, 5);
+        assert_eq!(a.manhattan(&b), 11);
+        assert_eq!(a.chebyshev(&b), 7);
+        assert_eq!(Point::new(2u8, 9).manhattan(&Point::new(7, 3)), 11);
+    }
+
+    #[test]
+    fn steps() {
+        let p = Point::<usize>::origin();
+        assert_eq!(p.checked_step(Direction::Up), None);
+        assert_eq!(p.step(Direction::Down), Point::new(0, 1));
+        assert_eq!(p.step_n(Direction::Right, 3), Point::new(3, 0));
+        assert_eq!(
+            Point::new(1, 1) - Point::new(2, 0) + Point::new(0, 1),
+            Point::new(-1, 2)
+        );
+    }
+}
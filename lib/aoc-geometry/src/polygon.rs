@@ -0,0 +1,71 @@
+//! Measurements of simple polygons with vertices on integer points.
+//!
+//! Polygons are given as their vertices in order around the boundary. The last vertex
+//! connects back to the first, so repeating the first vertex at the end is allowed but
+//! not required.
+
+use crate::point::abs_diff;
+use crate::{Coord, Point};
+
+fn edges<T>(vertices: &[Point<T>]) -> impl Iterator<Item = (&Point<T>, &Point<T>)> {
+    vertices.iter().zip(vertices.iter().cycle().skip(1))
+}
+
+/// Twice the area of a polygon, which is always an integer for integer vertices.
+/// Uses the https://en.wikipedia.org/wiki/Shoelace_formula
+pub fn double_area<T: Coord>(vertices: &[Point<T>]) -> T {
+    // sum the positive and negative halves separately so unsigned coordinates work too
+    let (pos, neg) = edges(vertices).fold((T::zero(), T::zero()), |(pos, neg), (a, b)| {
+        (pos + a.x * b.y, neg + b.x * a.y)
+    });
+
+    abs_diff(pos, neg)
+}
+
+/// Number of integer points on the boundary of a polygon, including the vertices
+pub fn boundary_points<T: Coord>(vertices: &[Point<T>]) -> T {
+    edges(vertices)
+        .map(|(a, b)| abs_diff(a.x, b.x).gcd(&abs_diff(a.y, b.y)))
+        .fold(T::zero(), |sum, n| sum + n)
+}
+
+/// Number of integer points strictly inside a polygon.
+/// Uses https://en.wikipedia.org/wiki/Pick%27s_theorem
+pub fn interior_points<T: Coord>(vertices: &[Point<T>]) -> T {
+    let two = T::one() + T::one();
+    (double_area(vertices) + two - boundary_points(vertices)) / two
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square() {
+        let square = [
+            Point::new(0usize, 0),
+            Point::new(4, 0),
+            Point::new(4, 4),
+            Point::new(0, 4),
+        ];
+
+        assert_eq!(double_area(&square), 32);
+        assert_eq!(boundary_points(&square), 16);
+        assert_eq!(interior_points(&square), 9);
+    }
+
+    #[test]
+    fn triangle() {
+        // closed with a repeated vertex and wound the other way
+        let triangle = [
+            Point::new(0, 0),
+            Point::new(-2, 3),
+            Point::new(4, 3),
+            Point::new(0, 0),
+        ];
+
+        assert_eq!(double_area(&triangle), 18);
+        assert_eq!(boundary_points(&triangle), 8);
+        assert_eq!(interior_points(&triangle), 6);
+    }
+}
@@ -0,0 +1,367 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+use anyhow::{anyhow, Error, Result};
+
+/// Offsets to the 4 orthogonally adjacent cells: up, down, left, right
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets to all 8 surrounding cells, including diagonals
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Fixed size 2-D grid of cells, stored row by row and indexed by (row, col)
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from cells in row-major order
+    pub fn new(rows: usize, cols: usize, cells: Vec<T>) -> Result<Self> {
+        if cells.len() != rows * cols {
+            return Err(anyhow!(
+                "Expected {} cells for a {rows}x{cols} grid, got {}",
+                rows * cols,
+                cells.len()
+            ));
+        }
+
+        Ok(Self { cells, rows, cols })
+    }
+
+    /// Create a grid with every cell set to the same value
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Parse a grid from lines of text, one cell per char.
+    /// All lines must be the same length.
+    pub fn parse<E: Display>(s: &str) -> Result<Self>
+    where
+        T: TryFrom<char, Error = E>,
+    {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = None;
+
+        for (row, line) in s.lines().enumerate() {
+            let len = line.chars().count();
+            match cols {
+                None => cols = Some(len),
+                Some(cols) if cols != len => {
+                    return Err(anyhow!(
+                        "Row {row} has {len} cells, expected {cols} like the first row"
+                    ));
+                }
+                _ => {}
+            }
+
+            for (col, c) in line.chars().enumerate() {
+                let cell = c
+                    .try_into()
+                    .map_err(|e| anyhow!("Invalid cell at row {row}, col {col}: {e:#}"))?;
+                cells.push(cell);
+            }
+
+            rows += 1;
+        }
+
+        let cols = cols.ok_or(anyhow!("Missing input"))?;
+
+        Ok(Self { cells, rows, cols })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Total number of cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Test if (row, col) is inside the grid
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.contains(row, col)
+            .then(|| &self.cells[row * self.cols + col])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.contains(row, col)
+            .then(|| &mut self.cells[row * self.cols + col])
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) {
+        self[(row, col)] = value;
+    }
+
+    /// Iterate over all cells in row-major order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Iterate over all cells in row-major order along with their (row, col)
+    pub fn enumerate(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> {
+        let cols = self.cols;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i / cols, i % cols), cell))
+    }
+
+    /// (row, col) of the first cell in row-major order matching the predicate
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| (i / self.cols, i % self.cols))
+    }
+
+    /// View of a single row
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// View of a single column, from top to bottom
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        (0..self.rows).map(move |row| &self.cells[row * self.cols + col])
+    }
+
+    /// Iterate over row views from top to bottom. A grid with no columns
+    /// still has its rows, which are all empty.
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        (0..self.rows).map(move |row| self.row(row))
+    }
+
+    /// Iterate over column views from left to right
+    pub fn iter_cols(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.cols).map(move |col| self.col(col))
+    }
+
+    /// (row, col) of the up to 4 cells orthogonally adjacent to (row, col)
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(row, col, &ORTHOGONAL)
+    }
+
+    /// (row, col) of the up to 8 cells surrounding (row, col), including diagonals
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offsets(row, col, &SURROUNDING)
+    }
+
+    /// Move from (row, col) by the given offset if the destination is inside the grid
+    pub fn offset(&self, row: usize, col: usize, dr: isize, dc: isize) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(dr)?;
+        let col = col.checked_add_signed(dc)?;
+
+        self.contains(row, col).then_some((row, col))
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        row: usize,
+        col: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&(dr, dc)| self.offset(row, col, dr, dc))
+    }
+
+    /// Create a new grid by applying a function to every cell
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Create a new grid with rows and columns swapped
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: self.iter_cols().flatten().cloned().collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Create a new grid rotated 90 degrees clockwise
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        // the first column, read bottom to top, becomes the first row
+        Self {
+            cells: self
+                .iter_cols()
+                .flat_map(|col| col.rev())
+                .cloned()
+                .collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Create a new grid rotated 90 degrees counter-clockwise
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        // the last column, read top to bottom, becomes the first row
+        Self {
+            cells: self.iter_cols().rev().flatten().cloned().collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            self.contains(row, col),
+            "({row}, {col}) is outside of {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            self.contains(row, col),
+            "({row}, {col}) is outside of {}x{} grid",
+            self.rows,
+            self.cols
+        );
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+impl<T, E> FromStr for Grid<T>
+where
+    T: TryFrom<char, Error = E>,
+    E: Display,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::parse(s)
+    }
+}
+
+/// Pretty print one row per line, with no separator between cells
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let grid = grid();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get(2, 0), None);
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn views() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.col(2).collect::<String>(), "cf");
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+        assert_eq!(grid.iter_rows().next_back(), Some(&['d', 'e', 'f'][..]));
+
+        // parsed from blank lines
+        let empty = "\n\n".parse::<Grid<char>>().unwrap();
+        assert_eq!((empty.rows(), empty.cols()), (2, 0));
+        assert_eq!(empty.iter_rows().collect::<Vec<_>>(), [&[], &[]]);
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8(1, 1).count(), 5);
+    }
+
+    #[test]
+    fn transform() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
@@ -0,0 +1,134 @@
+use std::ops::{Bound, Range, RangeBounds, RangeInclusive};
+
+use num::PrimInt;
+
+/// Half-open interval of integers from start up to but not including end.
+/// Unlike std::ops::Range it is Copy and Ord, and any interval with
+/// start >= end is empty.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// Interval including both start and end
+    pub fn inclusive(start: T, end: T) -> Self {
+        Self::new(start, end + T::one())
+    }
+
+    pub fn with_len(start: T, len: T) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Number of integers in the interval
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// Last integer in the interval, if it isn't empty
+    pub fn last(&self) -> Option<T> {
+        (!self.is_empty()).then(|| self.end - T::one())
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        self.start <= x && x < self.end
+    }
+
+    /// Test if every integer in other is also in self
+    pub fn contains_interval(&self, other: &Self) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Integers in both intervals, which may be empty
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Split into the integers below x and the integers at or above x,
+    /// either of which may be empty
+    pub fn split_at(&self, x: T) -> (Self, Self) {
+        let x = x.clamp(self.start, self.end.max(self.start));
+        (Self::new(self.start, x), Self::new(x, self.end))
+    }
+
+    /// Move the interval so that `from` ends up at `to`, keeping its length.
+    /// Works with unsigned integers as long as start >= from.
+    pub fn translate(&self, from: T, to: T) -> Self {
+        Self::new(self.start - from + to, self.end - from + to)
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for Interval<T> {
+    fn from(r: Range<T>) -> Self {
+        Self::new(r.start, r.end)
+    }
+}
+
+impl<T: PrimInt> From<RangeInclusive<T>> for Interval<T> {
+    fn from(r: RangeInclusive<T>) -> Self {
+        Self::inclusive(*r.start(), *r.end())
+    }
+}
+
+/// Allows e.g. BTreeSet::range(interval)
+impl<T> RangeBounds<T> for Interval<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        Bound::Included(&self.start)
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        Bound::Excluded(&self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basics() {
+        let a = Interval::inclusive(2, 6);
+        assert_eq!(a, Interval::from(2..7));
+        assert_eq!(a.len(), 5);
+        assert_eq!(a.last(), Some(6));
+        assert!(a.contains(6) && !a.contains(7));
+        assert_eq!(Interval::new(3u8, 1).len(), 0);
+    }
+
+    #[test]
+    fn intersection() {
+        let a = Interval::new(0, 10);
+        assert_eq!(a.intersection(&Interval::new(5, 15)), Interval::new(5, 10));
+        assert!(!a.overlaps(&Interval::new(10, 15)));
+        assert!(a.contains_interval(&Interval::new(3, 4)));
+    }
+
+    #[test]
+    fn split_and_translate() {
+        let a = Interval::new(10u32, 20);
+        assert_eq!(
+            a.split_at(15),
+            (Interval::new(10, 15), Interval::new(15, 20))
+        );
+        assert!(a.split_at(5).0.is_empty());
+        assert!(a.split_at(25).1.is_empty());
+        assert_eq!(a.translate(8, 0), Interval::new(2, 12));
+    }
+}
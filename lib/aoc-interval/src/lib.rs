@@ -0,0 +1,5 @@
+mod interval;
+mod set;
+
+pub use interval::Interval;
+pub use set::IntervalSet;
@@ -0,0 +1,210 @@
+use num::PrimInt;
+
+use crate::Interval;
+
+/// Set of integers stored as sorted, disjoint intervals.
+/// Touching intervals are merged, so each interval is separated from the next by a gap.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Add every integer in the interval to the set
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // absorb everything overlapping or touching the new interval
+        let mut merged = interval;
+        self.intervals.retain(|i| {
+            if i.start <= merged.end && merged.start <= i.end {
+                merged = Interval::new(merged.start.min(i.start), merged.end.max(i.end));
+                false
+            } else {
+                true
+            }
+        });
+
+        let idx = self.intervals.partition_point(|i| i.start < merged.start);
+        self.intervals.insert(idx, merged);
+    }
+
+    /// Remove every integer in the interval from the set
+    pub fn remove(&mut self, interval: Interval<T>) {
+        *self = self.difference(&interval.into());
+    }
+
+    /// Number of integers in the set
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |len, i| len + i.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, x: T) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end <= x);
+        self.intervals.get(idx).is_some_and(|i| i.contains(x))
+    }
+
+    /// Smallest integer in the set
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    /// Largest integer in the set
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().and_then(|i| i.last())
+    }
+
+    /// Iterate over the disjoint intervals in ascending order
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &Interval<T>> + ExactSizeIterator {
+        self.intervals.iter()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.extend(other.iter().copied());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Vec::new();
+        let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+
+        // walk both lists in order, always advancing whichever interval ends first
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let i = x.intersection(y);
+            if !i.is_empty() {
+                intersection.push(i);
+            }
+
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+
+        Self {
+            intervals: intersection,
+        }
+    }
+
+    /// Integers in self but not in other
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = Vec::new();
+
+        for a in self.iter() {
+            let mut start = a.start;
+
+            // cut out every interval of other overlapping this one
+            let first = other.intervals.partition_point(|b| b.end <= a.start);
+            for b in other.intervals[first..]
+                .iter()
+                .take_while(|b| b.start < a.end)
+            {
+                if start < b.start {
+                    difference.push(Interval::new(start, b.start));
+                }
+                start = b.end;
+            }
+
+            if start < a.end {
+                difference.push(Interval::new(start, a.end));
+            }
+        }
+
+        Self {
+            intervals: difference,
+        }
+    }
+
+    /// Split the set into the integers inside and outside of the interval
+    pub fn split(&self, by: &Interval<T>) -> (Self, Self) {
+        let by = Self::from(*by);
+        (self.intersection(&by), self.difference(&by))
+    }
+}
+
+impl<T: PrimInt> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: PrimInt> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: PrimInt> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, T> IntoIterator for &'a IntervalSet<T> {
+    type Item = &'a Interval<T>;
+    type IntoIter = std::slice::Iter<'a, Interval<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(intervals: &[(i32, i32)]) -> IntervalSet<i32> {
+        intervals
+            .iter()
+            .map(|&(s, e)| Interval::new(s, e))
+            .collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        let s = set(&[(5, 7), (0, 2), (2, 3), (10, 12), (6, 11)]);
+        assert_eq!(s, set(&[(0, 3), (5, 12)]));
+        assert_eq!(s.len(), 10);
+        assert!(s.contains(11) && !s.contains(4));
+        assert_eq!((s.min(), s.max()), (Some(0), Some(11)));
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 12), (14, 20)]);
+        assert_eq!(a.union(&b), set(&[(0, 20)]));
+        assert_eq!(a.intersection(&b), set(&[(3, 5), (10, 12), (14, 15)]));
+        assert_eq!(a.difference(&b), set(&[(0, 3), (12, 14)]));
+        assert_eq!(b.difference(&a), set(&[(5, 10), (15, 20)]));
+    }
+
+    #[test]
+    fn split() {
+        let (inside, outside) = set(&[(0, 5), (10, 15)]).split(&Interval::new(3, 11));
+        assert_eq!(inside, set(&[(3, 5), (10, 11)]));
+        assert_eq!(outside, set(&[(0, 3), (11, 15)]));
+    }
+}
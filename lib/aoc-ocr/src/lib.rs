@@ -0,0 +1,151 @@
+//! Read the block letters some puzzles draw as their answer.
+
+use anyhow::{anyhow, Result};
+use aoc_grid::Grid;
+
+/// Height of every letter in the font
+pub const LETTER_HEIGHT: usize = 6;
+
+/// Letters seen in answers so far, each drawn 6 rows tall with `#` for lit pixels
+const FONT: &[(char, [&str; LETTER_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Draw lit pixels as `#` and unlit ones as `.`, one row per line
+pub fn draw(screen: &Grid<bool>) -> String {
+    screen.map(|&lit| if lit { '#' } else { '.' }).to_string()
+}
+
+/// Columns of a letter: each is a bitmask of its lit rows, with the top row in bit 0
+fn letter_columns(rows: &[&str; LETTER_HEIGHT]) -> Vec<u8> {
+    (0..rows[0].len())
+        .map(|col| {
+            rows.iter()
+                .enumerate()
+                .filter(|(_, row)| row.as_bytes()[col] == b'#')
+                .fold(0, |mask, (row, _)| mask | 1 << row)
+        })
+        .collect()
+}
+
+/// Decode the letters drawn on a screen exactly one letter tall. Letters are
+/// separated by at least one unlit column.
+pub fn decode(screen: &Grid<bool>) -> Result<String> {
+    if screen.rows() != LETTER_HEIGHT {
+        return Err(anyhow!(
+            "Letters are {LETTER_HEIGHT} pixels tall, but the screen is {}",
+            screen.rows()
+        ));
+    }
+
+    let columns = screen
+        .iter_cols()
+        .map(|col| {
+            col.enumerate()
+                .filter(|(_, &lit)| lit)
+                .fold(0u8, |mask, (row, _)| mask | 1 << row)
+        })
+        .collect::<Vec<_>>();
+
+    let mut letters = String::new();
+    let mut start = 0;
+    while start < columns.len() {
+        if columns[start] == 0 {
+            start += 1;
+            continue;
+        }
+
+        let end = columns[start..]
+            .iter()
+            .position(|&mask| mask == 0)
+            .map_or(columns.len(), |len| start + len);
+        let letter = FONT
+            .iter()
+            .find(|(_, rows)| letter_columns(rows) == columns[start..end])
+            .map(|&(c, _)| c)
+            .ok_or_else(|| anyhow!("Unrecognized letter at column {start}:\n{}", draw(screen)))?;
+
+        letters.push(letter);
+        start = end;
+    }
+
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn screen(rows: &[&str]) -> Grid<bool> {
+        rows.join("\n")
+            .parse::<Grid<char>>()
+            .unwrap()
+            .map(|&c| c == '#')
+    }
+
+    /// Draw a word in the font, with a blank column between letters
+    fn word(word: &str) -> Grid<bool> {
+        let rows = (0..LETTER_HEIGHT)
+            .map(|row| {
+                word.chars()
+                    .map(|c| FONT.iter().find(|&&(f, _)| f == c).unwrap().1[row])
+                    .collect::<Vec<_>>()
+                    .join(".")
+            })
+            .collect::<Vec<_>>();
+        screen(&rows.iter().map(String::as_str).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn decodes() {
+        assert_eq!(decode(&word("HELLOKIYZ")).unwrap(), "HELLOKIYZ");
+
+        // padded with blank columns, like a CRT 40 pixels wide
+        let screen = screen(&[
+            "####.#..#.###..#..#.####.###..#..#.####.",
+            "#....#.#..#..#.#..#.#....#..#.#..#....#.",
+            "###..##...#..#.####.###..#..#.#..#...#..",
+            "#....#.#..###..#..#.#....###..#..#..#...",
+            "#....#.#..#.#..#..#.#....#....#..#.#....",
+            "####.#..#.#..#.#..#.####.#.....##..####.",
+        ]);
+        assert_eq!(decode(&screen).unwrap(), "EKRHEPUZ");
+    }
+
+    #[test]
+    fn every_letter() {
+        for &(c, rows) in FONT {
+            assert_eq!(decode(&screen(&rows)).unwrap(), c.to_string());
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert!(decode(&screen(&["#"; 5])).is_err());
+
+        let err = decode(&screen(&[
+            "#..#.#", "#..#.#", "####.#", "#..#.#", "#..#.#", "#..#..",
+        ]))
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Unrecognized letter at column 5"));
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::Display;
+use std::fs::read_to_string;
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+use aoc_visualize::Recorder;
+
+mod parameters;
+
+pub use parameters::{Parameters, PARAMETERS};
+
+/// Name of the expected answers file in each day's directory
+pub const EXPECTED_ANSWERS: &str = "expected.txt";
+
+/// A day's puzzle, solved in separate stages: the input is parsed once and
+/// then shared by both parts
+pub trait Solution {
+    /// Parsed puzzle input
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+
+    /// Parse the input with some parameters, for days whose examples are
+    /// solved differently from the real input. By default there are none.
+    fn parse_with(input: &str, parameters: &Parameters) -> Result<Self::Input> {
+        parameters.allow_only(&[])?;
+        Self::parse(input)
+    }
+
+    /// Parse the input and solve a single part, formatting the answer
+    fn solve(part: u8, input: &str) -> Result<String> {
+        Self::solve_with(part, input, &Parameters::default())
+    }
+
+    /// Like `solve`, with parameters for the input
+    fn solve_with(part: u8, input: &str, parameters: &Parameters) -> Result<String> {
+        let input = Self::parse_with(input, parameters)?;
+
+        match part {
+            1 => Self::part1(&input).map(|answer| answer.to_string()),
+            2 => Self::part2(&input).map(|answer| answer.to_string()),
+            _ => Err(anyhow!("Invalid part: {part}")),
+        }
+    }
+
+    /// Record frames of the simulation behind a part, for days that have one
+    fn visualize(part: u8, _input: &Self::Input, _recorder: &mut dyn Recorder) -> Result<()> {
+        Err(anyhow!("No visualization for part {part}"))
+    }
+}
+
+/// Check every answer listed in the expected answers file (`expected.txt`)
+/// in a day's directory. Each line names an input file in the same
+/// directory, a part and the answer, e.g. `example.txt 1 = 142`. Blank
+/// lines and lines starting with `#` are ignored. Inputs are solved with
+/// their parameters from `parameters.txt`, if any.
+pub fn check_answers<S: Solution>(dir: &str) -> Result<()> {
+    let dir = Path::new(dir);
+    let path = dir.join(EXPECTED_ANSWERS);
+    let expected =
+        read_to_string(&path).with_context(|| format!("Couldn't read {}", path.display()))?;
+
+    let mut failures = Vec::new();
+    for (i, line) in expected.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let (case, answer) =
+            line.split_once(" = ")
+                .ok_or(anyhow!("Missing answer on line {}: {}", i + 1, line))?;
+        let (file, part) =
+            case.split_once(' ')
+                .ok_or(anyhow!("Missing part on line {}: {}", i + 1, line))?;
+        let part = part
+            .parse()
+            .with_context(|| format!("Invalid part on line {}: {}", i + 1, line))?;
+
+        let input_path = dir.join(file);
+        let input = read_to_string(&input_path)
+            .with_context(|| format!("Couldn't read {}", input_path.display()))?;
+
+        let parameters = Parameters::for_input(&input_path)?;
+
+        match S::solve_with(part, &input, &parameters) {
+            Ok(actual) if actual == answer => {}
+            Ok(actual) => failures.push(format!(
+                "{file} part {part}: expected {answer}, got {actual}"
+            )),
+            Err(e) => failures.push(format!("{file} part {part}: {e:#}")),
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow!(failures.join("\n")))
+    }
+}
+
+/// Add a test checking a day's solution against its expected answers file
+#[macro_export]
+macro_rules! expected_answers {
+    ($solution:ty) => {
+        #[cfg(test)]
+        #[test]
+        fn expected_answers() {
+            if let Err(e) = $crate::check_answers::<$solution>(env!("CARGO_MANIFEST_DIR")) {
+                panic!("{e}");
+            }
+        }
+    };
+}
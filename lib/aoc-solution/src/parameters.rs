@@ -0,0 +1,133 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs::read_to_string;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{anyhow, Context, Error, Result};
+
+/// Name of the file in each day's directory giving parameters for some of
+/// its inputs
+pub const PARAMETERS: &str = "parameters.txt";
+
+/// Named values for puzzles whose examples are solved differently from the
+/// real input, e.g. counting a different row. Written as `row=10 search=20`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Parameters(BTreeMap<String, String>);
+
+impl Parameters {
+    /// Parameters for an input file, from the parameters file (`parameters.txt`)
+    /// in the same directory. Each line names an input file followed by its
+    /// parameters, e.g. `example.txt row=10`. Blank lines and lines starting
+    /// with `#` are ignored. Inputs without a line have no parameters.
+    pub fn for_input(path: &Path) -> Result<Self> {
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            return Ok(Self::default());
+        };
+        let file = path.with_file_name(PARAMETERS);
+        let lines = match read_to_string(&file) {
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            lines => lines.with_context(|| format!("Couldn't read {}", file.display()))?,
+        };
+
+        let parameters = lines
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .find_map(|line| match line.split_once(' ') {
+                Some((input, parameters)) if input == name => Some(parameters),
+                None if line == name => Some(""),
+                _ => None,
+            });
+
+        match parameters {
+            Some(parameters) => parameters
+                .parse()
+                .with_context(|| format!("Invalid parameters for {name} in {}", file.display())),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Fail if there are any parameters other than the given names
+    pub fn allow_only(&self, names: &[&str]) -> Result<()> {
+        match self.0.keys().find(|k| !names.contains(&k.as_str())) {
+            Some(name) => Err(anyhow!("Unknown parameter: {name}")),
+            None => Ok(()),
+        }
+    }
+
+    /// The value of a parameter, if it was given
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>>
+    where
+        T::Err: Display,
+    {
+        self.0
+            .get(name)
+            .map(|value| {
+                value
+                    .parse()
+                    .map_err(|e| anyhow!("Invalid parameter {name}={value}: {e}"))
+            })
+            .transpose()
+    }
+}
+
+impl FromStr for Parameters {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        s.split_whitespace()
+            .map(|p| {
+                p.split_once('=')
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .ok_or(anyhow!("Expected name=value, got {p}"))
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn parameters() {
+        let parameters: Parameters = "row=10 search=20".parse().unwrap();
+        assert_eq!(parameters.get::<isize>("row").unwrap(), Some(10));
+        assert_eq!(parameters.get::<isize>("missing").unwrap(), None);
+        assert!(parameters.allow_only(&["row", "search"]).is_ok());
+        assert!(parameters.allow_only(&["row"]).is_err());
+        assert!("row=ten"
+            .parse::<Parameters>()
+            .unwrap()
+            .get::<isize>("row")
+            .is_err());
+        assert!("row".parse::<Parameters>().is_err());
+    }
+
+    #[test]
+    fn for_input() {
+        let dir = std::env::temp_dir().join(format!("aoc-parameters-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        assert_eq!(
+            Parameters::for_input(&dir.join("example.txt")).unwrap(),
+            Parameters::default()
+        );
+
+        fs::write(
+            dir.join(PARAMETERS),
+            "# comment\nexample.txt row=10\nexample2.txt\n",
+        )
+        .unwrap();
+        let parameters = |name| Parameters::for_input(&dir.join(name)).unwrap();
+        assert_eq!(parameters("example.txt"), "row=10".parse().unwrap());
+        assert_eq!(parameters("example2.txt"), Parameters::default());
+        assert_eq!(parameters("input.txt"), Parameters::default());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,134 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::{Frame, Rgb};
+
+/// RGB bytes of a frame drawn with each cell as a scale x scale square,
+/// padded with black to width x height pixels
+fn pixels(frame: &Frame, scale: usize, width: usize, height: usize) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            let Rgb(r, g, b) = frame
+                .get(y / scale, x / scale)
+                .map_or(Rgb::BLACK, |p| p.color);
+            pixels.extend([r, g, b]);
+        }
+    }
+    pixels
+}
+
+fn create(path: &Path) -> Result<BufWriter<File>> {
+    File::create(path)
+        .map(BufWriter::new)
+        .with_context(|| format!("Couldn't create {}", path.display()))
+}
+
+/// Write a frame as a binary PPM image
+pub fn write_ppm(frame: &Frame, scale: usize, path: &Path) -> Result<()> {
+    let (width, height) = (frame.cols() * scale, frame.rows() * scale);
+
+    let mut out = create(path)?;
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    out.write_all(&pixels(frame, scale, width, height))?;
+    out.flush()?;
+
+    Ok(())
+}
+
+/// Write a frame as a PNG image
+pub fn write_png(frame: &Frame, scale: usize, path: &Path) -> Result<()> {
+    let (width, height) = (frame.cols() * scale, frame.rows() * scale);
+
+    let mut encoder = png::Encoder::new(create(path)?, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()?
+        .write_image_data(&pixels(frame, scale, width, height))?;
+
+    Ok(())
+}
+
+/// Write an animated GIF that loops forever, showing each frame for delay_ms.
+/// Frames smaller than the largest are padded with black.
+pub fn write_gif(frames: &[&Frame], scale: usize, delay_ms: u16, path: &Path) -> Result<()> {
+    let width = frames.iter().map(|f| f.cols()).max().unwrap_or_default() * scale;
+    let height = frames.iter().map(|f| f.rows()).max().unwrap_or_default() * scale;
+    let (Ok(w), Ok(h)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(anyhow!("{width}x{height} is too big for a GIF"));
+    };
+
+    let mut encoder = gif::Encoder::new(create(path)?, w, h, &[])?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+    for frame in frames {
+        let pixels = pixels(frame, scale, width, height);
+        let mut gif_frame = gif::Frame::from_rgb_speed(w, h, &pixels, 10);
+        // in hundredths of a second
+        gif_frame.delay = delay_ms / 10;
+        encoder.write_frame(&gif_frame)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pixel;
+    use aoc_grid::Grid;
+
+    fn frame() -> Frame {
+        let mut frame = Grid::filled(1, 2, Pixel::new('.', Rgb::BLACK));
+        frame[(0, 1)] = Pixel::new('#', Rgb::WHITE);
+        frame
+    }
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("aoc-visualize-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn scales_and_pads() {
+        let pixels = pixels(&frame(), 2, 5, 2);
+        let row = [0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 0, 0, 0];
+        assert_eq!(pixels, [row, row].concat());
+    }
+
+    #[test]
+    fn ppm() {
+        let path = temp_path("frame.ppm");
+        write_ppm(&frame(), 1, &path).unwrap();
+        let data = std::fs::read(&path).unwrap();
+        assert_eq!(data, b"P6\n2 1\n255\n\0\0\0\xff\xff\xff");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn png_and_gif() {
+        let path = temp_path("frame.png");
+        write_png(&frame(), 3, &path).unwrap();
+        let decoder = png::Decoder::new(File::open(&path).unwrap());
+        let info = decoder.read_info().unwrap().info().clone();
+        assert_eq!((info.width, info.height), (6, 3));
+        std::fs::remove_file(path).unwrap();
+
+        let path = temp_path("frames.gif");
+        let (small, big) = (frame(), Grid::filled(2, 2, Pixel::default()));
+        write_gif(&[&small, &big], 2, 100, &path).unwrap();
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(File::open(&path).unwrap())
+            .unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (4, 4));
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 10);
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,224 @@
+//! Record frames of a grid simulation as it runs, then play them back in the
+//! terminal or export them as images.
+
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use aoc_grid::Grid;
+
+mod image;
+mod terminal;
+
+pub use image::{write_gif, write_png, write_ppm};
+pub use terminal::play;
+
+/// 24-bit color
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const GRAY: Self = Self(128, 128, 128);
+    pub const RED: Self = Self(220, 50, 47);
+    pub const GREEN: Self = Self(133, 153, 0);
+    pub const BLUE: Self = Self(38, 139, 210);
+    pub const YELLOW: Self = Self(255, 215, 0);
+
+    /// Shade of gray, from black at 0 to white at 255
+    pub fn gray(level: u8) -> Self {
+        Self(level, level, level)
+    }
+}
+
+/// One cell of a frame: a glyph for the terminal and a color for both the
+/// terminal and images
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pixel {
+    pub glyph: char,
+    pub color: Rgb,
+}
+
+impl Pixel {
+    pub fn new(glyph: char, color: Rgb) -> Self {
+        Self { glyph, color }
+    }
+}
+
+impl Default for Pixel {
+    fn default() -> Self {
+        Self::new(' ', Rgb::BLACK)
+    }
+}
+
+/// Snapshot of a simulation's state
+pub type Frame = Grid<Pixel>;
+
+/// Receives a frame for each step of a simulation
+pub trait Recorder {
+    /// Whether frames are wanted at all, so simulations can skip drawing them
+    fn is_recording(&self) -> bool {
+        true
+    }
+
+    fn record(&mut self, frame: Frame);
+}
+
+impl dyn Recorder + '_ {
+    /// Record the frame drawn by `draw`, only drawing it if it's wanted
+    pub fn draw(&mut self, draw: impl FnOnce() -> Frame) {
+        if self.is_recording() {
+            self.record(draw());
+        }
+    }
+}
+
+/// Discards every frame, for running simulations without visualizing them
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoRecorder;
+
+impl Recorder for NoRecorder {
+    fn is_recording(&self) -> bool {
+        false
+    }
+
+    fn record(&mut self, _frame: Frame) {}
+}
+
+/// Keeps at most a fixed number of frames, evenly spaced over the whole
+/// simulation, plus the final frame
+#[derive(Clone, Debug)]
+pub struct Recording {
+    frames: Vec<Frame>,
+    max_frames: usize,
+    /// keep every stride-th frame
+    stride: usize,
+    seen: usize,
+    last: Option<Frame>,
+}
+
+impl Recording {
+    pub fn new(max_frames: usize) -> Self {
+        assert!(
+            max_frames >= 2,
+            "Recording needs room for at least 2 frames"
+        );
+        Self {
+            frames: Vec::new(),
+            max_frames,
+            stride: 1,
+            seen: 0,
+            last: None,
+        }
+    }
+
+    /// Total number of frames recorded, including those that weren't kept
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// The kept frames, ending with the final frame recorded
+    pub fn frames(&self) -> Vec<&Frame> {
+        let mut frames = self.frames.iter().collect::<Vec<_>>();
+        if self.seen > 0 && !(self.seen - 1).is_multiple_of(self.stride) {
+            frames.extend(&self.last);
+        }
+        frames
+    }
+
+    /// Save the frames according to the output's extension: a `.gif` is
+    /// animated, while `.png` and `.ppm` write one numbered file per frame,
+    /// e.g. `beam.png` becomes `beam-0000.png`, `beam-0001.png`, ...
+    pub fn save(&self, output: &Path, scale: usize, delay_ms: u16) -> Result<()> {
+        let frames = self.frames();
+        if frames.is_empty() {
+            return Err(anyhow!("No frames recorded"));
+        }
+
+        let ext = output.extension().and_then(|e| e.to_str());
+        match ext {
+            Some("gif") => write_gif(&frames, scale, delay_ms, output),
+            Some("png" | "ppm") => {
+                let stem = output
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .ok_or(anyhow!("Invalid output: {}", output.display()))?;
+
+                for (i, frame) in frames.iter().enumerate() {
+                    let path = output.with_file_name(format!("{stem}-{i:04}.{}", ext.unwrap()));
+                    match ext {
+                        Some("png") => write_png(frame, scale, &path)?,
+                        _ => write_ppm(frame, scale, &path)?,
+                    }
+                }
+
+                Ok(())
+            }
+            _ => Err(anyhow!(
+                "Unsupported output {}: expected .gif, .png or .ppm",
+                output.display()
+            )),
+        }
+    }
+}
+
+impl Recorder for Recording {
+    fn record(&mut self, frame: Frame) {
+        if self.seen.is_multiple_of(self.stride) {
+            self.frames.push(frame.clone());
+        }
+        self.seen += 1;
+        self.last = Some(frame);
+
+        // out of room: thin out the frames kept so far and keep fewer from now on
+        if self.frames.len() > self.max_frames {
+            self.frames = self.frames.drain(..).step_by(2).collect();
+            self.stride *= 2;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 1x1 frame with the given glyph
+    fn frame(glyph: char) -> Frame {
+        Grid::filled(1, 1, Pixel::new(glyph, Rgb::WHITE))
+    }
+
+    fn glyphs(recording: &Recording) -> String {
+        recording.frames().iter().map(|f| f[(0, 0)].glyph).collect()
+    }
+
+    #[test]
+    fn thins_frames() {
+        let mut recording = Recording::new(4);
+        for c in 'a'..='j' {
+            recording.record(frame(c));
+        }
+
+        // every 4th frame, plus the last
+        assert_eq!(recording.seen(), 10);
+        assert_eq!(glyphs(&recording), "aeij");
+
+        recording.record(frame('k'));
+        assert_eq!(glyphs(&recording), "aeik");
+    }
+
+    #[test]
+    fn skips_drawing() {
+        let mut drawn = false;
+        let recorder: &mut dyn Recorder = &mut NoRecorder;
+        recorder.draw(|| {
+            drawn = true;
+            frame('a')
+        });
+        assert!(!drawn);
+
+        let mut recording = Recording::new(2);
+        let recorder: &mut dyn Recorder = &mut recording;
+        recorder.draw(|| frame('a'));
+        assert_eq!(glyphs(&recording), "a");
+    }
+}
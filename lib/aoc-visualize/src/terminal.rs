@@ -0,0 +1,65 @@
+use std::io::Write;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::{Frame, Rgb};
+
+const CLEAR: &str = "\x1b[2J";
+const HOME: &str = "\x1b[H";
+const RESET: &str = "\x1b[0m";
+
+/// Draw a frame with ANSI true color escapes, only changing color when needed
+fn draw(frame: &Frame, out: &mut impl Write) -> Result<()> {
+    for row in frame.iter_rows() {
+        let mut color = None;
+        for pixel in row {
+            if color != Some(pixel.color) {
+                let Rgb(r, g, b) = pixel.color;
+                write!(out, "\x1b[38;2;{r};{g};{b}m")?;
+                color = Some(pixel.color);
+            }
+            write!(out, "{}", pixel.glyph)?;
+        }
+        writeln!(out, "{RESET}")?;
+    }
+
+    Ok(())
+}
+
+/// Animate frames in a terminal, redrawing in place every delay
+pub fn play(frames: &[&Frame], delay: Duration, out: &mut impl Write) -> Result<()> {
+    write!(out, "{CLEAR}")?;
+    for (i, frame) in frames.iter().enumerate() {
+        if i > 0 {
+            thread::sleep(delay);
+        }
+
+        write!(out, "{HOME}")?;
+        draw(frame, out)?;
+        out.flush()?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pixel;
+    use aoc_grid::Grid;
+
+    #[test]
+    fn escapes() {
+        let mut frame = Grid::filled(2, 2, Pixel::new('.', Rgb::BLACK));
+        frame[(1, 1)] = Pixel::new('#', Rgb::WHITE);
+
+        let mut out = Vec::new();
+        play(&[&frame], Duration::ZERO, &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[2J\x1b[H\x1b[38;2;0;0;0m..\x1b[0m\n\x1b[38;2;0;0;0m.\x1b[38;2;255;255;255m#\x1b[0m\n"
+        );
+    }
+}